@@ -39,6 +39,8 @@ pub struct StructDefinition {
     pub type_params: Vec<TypeParameter>,
     pub fields: Vec<StructField>,
     pub methods: Vec<Function>,
+    pub is_packed: bool, // `Name: packed { ... }` - no padding between fields
+    pub is_union: bool,  // `Name: union { ... }` - all fields share the same storage
     pub span: Option<Span>,
 }
 
@@ -48,6 +50,7 @@ pub struct StructField {
     pub type_: AstType,
     pub is_mutable: bool,
     pub default_value: Option<Expression>,
+    pub bit_width: Option<u32>, // `flags: u8 : 3` - bitfield packed into a shared storage unit
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -57,6 +60,7 @@ pub struct EnumDefinition {
     pub variants: Vec<EnumVariant>,
     pub methods: Vec<Function>,
     pub required_traits: Vec<String>, // Traits that all variants must implement (.requires())
+    pub repr: Option<AstType>, // Integer representation for FFI: `Name: repr(i32) A, B`
     pub span: Option<Span>,
 }
 
@@ -142,6 +146,26 @@ pub enum Declaration {
     },
     Export {
         symbols: Vec<String>,
+        /// Module the symbols are re-exported from (`@export { foo } from @std.io`)
+        from: Option<String>,
     },
     TypeAlias(TypeAlias),
 }
+
+impl Declaration {
+    /// Name of the top-level item this declares, if it introduces one
+    pub fn declared_name(&self) -> Option<&str> {
+        match self {
+            Declaration::Function(func) => Some(&func.name),
+            Declaration::ExternalFunction(ext) => Some(&ext.name),
+            Declaration::Struct(def) => Some(&def.name),
+            Declaration::Enum(def) => Some(&def.name),
+            Declaration::Behavior(def) => Some(&def.name),
+            Declaration::Trait(def) => Some(&def.name),
+            Declaration::Constant { name, .. } => Some(name),
+            Declaration::TypeAlias(alias) => Some(&alias.name),
+            Declaration::ModuleImport { alias, .. } => Some(alias),
+            _ => None,
+        }
+    }
+}
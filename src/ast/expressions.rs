@@ -28,6 +28,13 @@ pub enum BinaryOperator {
     ShiftRight,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnaryOperator {
+    Negate,     // -x (integer or float)
+    BitwiseNot, // ~x (integer complement)
+    Not,        // !x (boolean negation)
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Expression {
     #[allow(dead_code)]
@@ -56,6 +63,10 @@ pub enum Expression {
         op: BinaryOperator,
         right: Box<Expression>,
     },
+    UnaryOp {
+        op: UnaryOperator,
+        operand: Box<Expression>,
+    },
     FunctionCall {
         name: String,
         type_args: Vec<AstType>,
@@ -123,7 +134,8 @@ pub enum Expression {
     StringLength(Box<Expression>),
     // Option<T> constructors
     Some(Box<Expression>), // Some(value)
-    None,                  // None (also accessible as "null")
+    None,                  // None
+    Null,                  // null pointer literal, typed by the expected pointer type
     // String interpolation: "Hello ${name}!"
     StringInterpolation {
         parts: Vec<StringPart>,
@@ -22,6 +22,8 @@ pub use primitives::{
 pub use statements::*;
 pub use types::*;
 
+use crate::error::CompileError;
+
 /// Root AST node representing a complete Zen program
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct Program {
@@ -47,6 +49,43 @@ impl Program {
         }
     }
 
+    /// Append the declarations of another source file's program.
+    /// Imports both files share are kept once; any other name defined in both
+    /// is a `DuplicateDeclaration` error.
+    pub fn merge(&mut self, other: Program) -> Result<(), CompileError> {
+        let existing = self.declarations.len();
+        for decl in other.declarations {
+            if let Declaration::ModuleImport {
+                alias, module_path, ..
+            } = &decl
+            {
+                let already_imported = self.declarations[..existing].iter().any(|d| {
+                    matches!(d, Declaration::ModuleImport { alias: a, module_path: p, .. }
+                        if a == alias && p == module_path)
+                });
+                if already_imported {
+                    continue;
+                }
+            }
+
+            if let Some(name) = decl.declared_name() {
+                let first = self.declarations[..existing]
+                    .iter()
+                    .find(|d| d.declared_name() == Some(name));
+                if let Some(first) = first {
+                    return Err(CompileError::DuplicateDeclaration {
+                        name: name.to_string(),
+                        first_location: declaration_span(first),
+                        duplicate_location: declaration_span(&decl),
+                    });
+                }
+            }
+            self.declarations.push(decl);
+        }
+        self.statements.extend(other.statements);
+        Ok(())
+    }
+
     #[allow(dead_code)]
     pub fn functions(&self) -> impl Iterator<Item = &Function> {
         self.declarations.iter().filter_map(|decl| {
@@ -58,3 +97,14 @@ impl Program {
         })
     }
 }
+
+/// Source location of a declaration, for the kinds that record one
+fn declaration_span(decl: &Declaration) -> Option<crate::error::Span> {
+    match decl {
+        Declaration::Constant { span, .. } | Declaration::ModuleImport { span, .. } => {
+            span.clone()
+        }
+        Declaration::TypeAlias(alias) => alias.span.clone(),
+        _ => None,
+    }
+}
@@ -232,6 +232,11 @@ impl<'ctx> LLVMCompiler<'ctx> {
                     let actual_type = if param_name == "self" {
                         let resolved = self.resolve_self_type(param_type, type_name, &[]);
                         AstType::ptr(resolved)
+                    } else if param_value.is_pointer_value()
+                        && matches!(self.to_llvm_type(param_type), Ok(super::Type::Struct(_)))
+                    {
+                        // Struct arguments arrive by pointer, like `self`
+                        AstType::ptr(param_type.clone())
                     } else {
                         param_type.clone()
                     };
@@ -327,6 +332,12 @@ impl<'ctx> LLVMCompiler<'ctx> {
             }
         }
 
+        // Built-in methods on raw/mutable pointers (offset, is_null, is_not_null).
+        // Ptr<T> methods are left to stdlib/core/ptr.zen.
+        if let Some(result) = self.try_compile_pointer_method(object, method_name, args)? {
+            return Ok(result);
+        }
+
         // NOTE: Range constructors and methods are now in stdlib/core/iterator.zen
         // HashMap methods use stdlib Zen implementation via normal resolution
 
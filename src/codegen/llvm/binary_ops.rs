@@ -287,7 +287,7 @@ impl<'ctx> LLVMCompiler<'ctx> {
         }
     }
 
-    fn compile_string_compare(
+    pub(super) fn compile_string_compare(
         &mut self,
         left: BasicValueEnum<'ctx>,
         right: BasicValueEnum<'ctx>,
@@ -36,6 +36,7 @@ impl<'ctx> LLVMCompiler<'ctx> {
                     payload: None,
                 },
             ],
+            repr: None,
         };
         self.symbols
             .insert(wk.option_name(), symbols::Symbol::EnumType(option_info));
@@ -67,6 +68,7 @@ impl<'ctx> LLVMCompiler<'ctx> {
                     }),
                 },
             ],
+            repr: None,
         };
         self.symbols
             .insert(wk.result_name(), symbols::Symbol::EnumType(result_info));
@@ -0,0 +1,94 @@
+//! Function-level execution counters for `--instrument-coverage`
+//!
+//! Every compiled function gets an i64 global that its entry block increments.
+//! When `main` returns, the count of each function is written to stderr as
+//! `coverage: <function> <count>`, including functions that never ran.
+
+use super::LLVMCompiler;
+use crate::error::CompileError;
+use inkwell::module::Linkage;
+use inkwell::values::{FunctionValue, GlobalValue, InstructionOpcode};
+use inkwell::AddressSpace;
+
+impl<'ctx> LLVMCompiler<'ctx> {
+    /// Count a call of `function_name`; the builder must be at the start of its entry block
+    pub(crate) fn build_coverage_counter(&mut self, function_name: &str) -> Result<(), CompileError> {
+        if !self.coverage {
+            return Ok(());
+        }
+        let i64_type = self.context.i64_type();
+        let counter = self.module.add_global(i64_type, None, &format!("__zen_cov.{}", function_name));
+        counter.set_linkage(Linkage::Internal);
+        counter.set_initializer(&i64_type.const_zero());
+
+        let count = self
+            .builder
+            .build_load(i64_type, counter.as_pointer_value(), "cov_count")?
+            .into_int_value();
+        let count = self.builder.build_int_add(count, i64_type.const_int(1, false), "cov_inc")?;
+        self.builder.build_store(counter.as_pointer_value(), count)?;
+
+        self.coverage_counters.push((function_name.to_string(), counter));
+        Ok(())
+    }
+
+    /// Print every counter before each `ret` in `main`. Call once all functions are compiled.
+    pub(crate) fn build_coverage_report(&mut self) -> Result<(), CompileError> {
+        if !self.coverage {
+            return Ok(());
+        }
+        let Some(main_fn) = self.module.get_function("main") else {
+            return Ok(());
+        };
+        let report_fn = self.define_coverage_report_fn()?;
+
+        for block in main_fn.get_basic_blocks() {
+            if let Some(terminator) = block.get_terminator() {
+                if terminator.get_opcode() == InstructionOpcode::Return {
+                    self.builder.position_before(&terminator);
+                    self.builder.build_call(report_fn, &[], "")?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn define_coverage_report_fn(&mut self) -> Result<FunctionValue<'ctx>, CompileError> {
+        let ptr_type = self.context.ptr_type(AddressSpace::default());
+        let fprintf = self.module.get_function("fprintf").unwrap_or_else(|| {
+            let fn_type = self.context.i32_type().fn_type(&[ptr_type.into(), ptr_type.into()], true);
+            self.module.add_function("fprintf", fn_type, None)
+        });
+        let stderr_global = self
+            .module
+            .get_global("stderr")
+            .unwrap_or_else(|| self.module.add_global(ptr_type, None, "stderr"));
+
+        let report_fn = self.module.add_function(
+            "__zen_coverage_report",
+            self.context.void_type().fn_type(&[], false),
+            Some(Linkage::Internal),
+        );
+        self.builder.position_at_end(self.context.append_basic_block(report_fn, "entry"));
+
+        let stderr_ptr = self.builder.build_load(ptr_type, stderr_global.as_pointer_value(), "stderr")?;
+        let format = self.builder.build_global_string_ptr("coverage: %s %lld\n", "cov_format")?;
+        let counters: Vec<(String, GlobalValue<'ctx>)> = self.coverage_counters.clone();
+        for (name, counter) in counters {
+            let name_ptr = self.builder.build_global_string_ptr(&name, "cov_name")?;
+            let count = self.builder.build_load(self.context.i64_type(), counter.as_pointer_value(), "count")?;
+            self.builder.build_call(
+                fprintf,
+                &[
+                    stderr_ptr.into(),
+                    format.as_pointer_value().into(),
+                    name_ptr.as_pointer_value().into(),
+                    count.into(),
+                ],
+                "",
+            )?;
+        }
+        self.builder.build_return(None)?;
+        Ok(report_fn)
+    }
+}
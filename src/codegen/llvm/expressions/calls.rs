@@ -120,10 +120,14 @@ pub fn compile_closure<'ctx>(
                 .get_terminator()
                 .is_none()
             {
-                if matches!(ret_type, AstType::Void) {
-                    compiler.builder.build_return(None)?;
-                } else {
-                    compiler.builder.build_return(Some(&result))?;
+                match function_type.get_return_type() {
+                    Some(expected) => {
+                        let result = compiler.cast_value_to_type(result, expected)?;
+                        compiler.builder.build_return(Some(&result))?;
+                    }
+                    None => {
+                        compiler.builder.build_return(None)?;
+                    }
                 }
             }
 
@@ -62,7 +62,7 @@ pub fn compile_loop<'ctx>(
         if has_return_value {
             Ok(body_value)
         } else {
-            Ok(compiler.context.i64_type().const_zero().into())
+            Ok(compiler.unit_value())
         }
     } else {
         Err(CompileError::InternalError(
@@ -96,7 +96,7 @@ pub fn compile_break<'ctx>(
             compiler.compile_expression(val_expr)?
         } else {
             // No value - return void
-            compiler.context.i64_type().const_zero().into()
+            compiler.unit_value()
         };
 
         // Branch to break target
@@ -140,7 +140,7 @@ pub fn compile_continue<'ctx>(
             .map_err(CompileError::from)?;
 
         // Return void (though we've already branched)
-        Ok(compiler.context.i64_type().const_zero().into())
+        Ok(compiler.unit_value())
     } else {
         Err(CompileError::InternalError(
             "Expected Continue expression".to_string(),
@@ -172,8 +172,7 @@ pub fn compile_return<'ctx>(
         // Build return instruction - use None for void functions
         if is_void_function {
             compiler.builder.build_return(None)?;
-            // For void functions, return a dummy value
-            Ok(compiler.context.i64_type().const_zero().into())
+            Ok(compiler.unit_value())
         } else {
             // Cast return value to match function return type using shared helper
             let final_value = if let Some(func) = compiler.current_function {
@@ -204,7 +204,10 @@ pub fn compile_enum_variant<'ctx>(
             // ================================================================
             let ptr_type = compiler.context.ptr_type(AddressSpace::default());
 
-            let payload_value = if compiled.is_pointer_value() {
+            let payload_value = if compiler.is_unit_value(compiled) {
+                // Unit payloads (`Result.Ok(())`) carry nothing
+                ptr_type.const_null().into()
+            } else if compiled.is_pointer_value() {
                 // Pointer values: store directly (already the right type)
                 compiled
             } else if compiled.is_int_value() {
@@ -223,6 +223,7 @@ pub fn infer_expression_type(
                 Ok(AstType::Void)
             }
         }
+        Expression::UnaryOp { operand, .. } => compiler.infer_expression_type(operand),
         Expression::BinaryOp { op, left, right } => {
             // Binary operations return different types based on the operator
             use crate::ast::BinaryOperator;
@@ -297,6 +298,7 @@ pub fn infer_expression_type(
                 })
             }
         }
+        Expression::Null => Ok(AstType::raw_ptr(AstType::Void)),
         Expression::StructLiteral { name, .. } => {
             // Get the struct type fields from registered types
             if let Some(struct_info) = compiler.struct_types.get(name) {
@@ -760,6 +762,11 @@ fn infer_method_call_type(
                 return Ok(return_type);
             }
         }
+        let is_core = name == "core"
+            || compiler.module_imports.get(name) == Some(&compiler.get_module_id("core"));
+        if is_core && method == "offset_of" {
+            return Ok(AstType::Usize);
+        }
     }
 
     // Handle raise method
@@ -767,6 +774,15 @@ fn infer_method_call_type(
         return infer_raise_method_type(compiler, object);
     }
 
+    // Built-in raw/mutable pointer methods
+    if matches!(method, "offset" | "is_null" | "is_not_null") {
+        if let Ok(object_type) = compiler.infer_expression_type(object) {
+            if object_type.is_mutable_ptr() || object_type.is_raw_ptr() {
+                return Ok(if method == "offset" { object_type } else { AstType::Bool });
+            }
+        }
+    }
+
     // Handle constructors (including generic constructors like new<K,V>)
     let base_method = if let Some(angle_pos) = method.find('<') {
         &method[..angle_pos]
@@ -23,7 +23,7 @@ pub fn compile_literal<'ctx>(
             .bool_type()
             .const_int(if *v { 1 } else { 0 }, false)
             .into()),
-        Expression::Unit => Ok(compiler.context.i32_type().const_zero().into()),
+        Expression::Unit => Ok(compiler.unit_value()),
         Expression::String(s) => compiler.compile_string_literal(s),
         _ => Err(CompileError::InternalError(
             format!("Expected literal, got {:?}", expr),
@@ -46,6 +46,7 @@ impl<'ctx> LLVMCompiler<'ctx> {
 
             // Operations
             Expression::BinaryOp { .. } => operations::compile_binary_operation(self, expr),
+            Expression::UnaryOp { .. } => operations::compile_unary_operation(self, expr),
             Expression::TypeCast { .. } => operations::compile_type_cast(self, expr),
 
             // Function calls
@@ -68,6 +69,11 @@ impl<'ctx> LLVMCompiler<'ctx> {
             Expression::None => enums::compile_none(self),
 
             // Collections - deprecated syntax, all use stdlib/vec.zen now
+            Expression::ArrayLiteral(elements) if elements.is_empty() => Err(CompileError::TypeError(
+                "An empty '[]' has no element type or runtime representation; create the collection with `Vec<T>.new(allocator)`"
+                    .to_string(),
+                self.get_current_span(),
+            )),
             Expression::ArrayLiteral(_)
             | Expression::ArrayIndex { .. }
             | Expression::VecConstructor { .. }
@@ -106,11 +112,24 @@ impl<'ctx> LLVMCompiler<'ctx> {
             Expression::PointerAddress(inner) => self.compile_pointer_to_int(inner),
             Expression::CreateReference(inner) => self.compile_address_of(inner),
             Expression::CreateMutableReference(inner) => self.compile_address_of(inner),
-
-            _ => Err(CompileError::InternalError(
-                format!("Unhandled expression type: {:?}", expr),
-                None,
-            )),
+            Expression::Null => Ok(self.compile_null_pointer()),
+
+            // Parsed and type-checked, but codegen has no lowering for them yet
+            Expression::CollectionLoop { .. } => Err(unimplemented_expression(self, "collection.loop()")),
+            Expression::StringLength(_) => Err(unimplemented_expression(self, "string length")),
+            Expression::PatternMatch { .. } => Err(unimplemented_expression(self, "pattern match")),
+            Expression::Defer(_) => Err(unimplemented_expression(self, "@this.defer()")),
+
+            // These are rewritten into calls by the parser; reaching codegen is a compiler bug
+            Expression::StdReference | Expression::BuiltinReference | Expression::ThisReference => {
+                Err(CompileError::InternalError(
+                    format!(
+                        "unresolved module reference {:?} reached codegen (this is a compiler bug, please report it)",
+                        expr
+                    ),
+                    self.get_current_span(),
+                ))
+            }
         }
     }
 
@@ -146,6 +165,14 @@ impl<'ctx> LLVMCompiler<'ctx> {
     }
 }
 
+/// Error for an expression the language accepts but codegen cannot lower yet
+fn unimplemented_expression(compiler: &LLVMCompiler, what: &str) -> CompileError {
+    CompileError::UnsupportedFeature(
+        format!("{} expressions are not yet implemented in code generation", what),
+        compiler.get_current_span(),
+    )
+}
+
 /// Compile a block expression - executes statements and returns the last expression's value
 fn compile_block_expression<'ctx>(
     compiler: &mut LLVMCompiler<'ctx>,
@@ -154,8 +181,8 @@ fn compile_block_expression<'ctx>(
     use crate::ast::Statement;
 
     if statements.is_empty() {
-        // Empty block returns void/unit (i32 0 as placeholder)
-        return Ok(compiler.context.i32_type().const_int(0, false).into());
+        // Empty block is a void expression
+        return Ok(compiler.unit_value());
     }
 
     // Compile all statements except the last one
@@ -174,13 +201,13 @@ fn compile_block_expression<'ctx>(
             // If the last statement is a return, compile it normally
             // The actual return happens in the statement, but we need to return something here
             compiler.compile_statement(last_stmt)?;
-            // Return a dummy value since the return already happened
-            Ok(compiler.context.i32_type().const_int(0, false).into())
+            // The return already happened; the block itself has no value
+            Ok(compiler.unit_value())
         }
         _ => {
             // Other statements don't produce a value, compile and return void
             compiler.compile_statement(last_stmt)?;
-            Ok(compiler.context.i32_type().const_int(0, false).into())
+            Ok(compiler.unit_value())
         }
     }
 }
@@ -1,5 +1,5 @@
 use super::super::LLVMCompiler;
-use crate::ast::Expression;
+use crate::ast::{Expression, UnaryOperator};
 use crate::error::CompileError;
 use inkwell::values::BasicValueEnum;
 
@@ -18,6 +18,42 @@ pub fn compile_binary_operation<'ctx>(
     }
 }
 
+pub fn compile_unary_operation<'ctx>(
+    compiler: &mut LLVMCompiler<'ctx>,
+    expr: &Expression,
+) -> Result<BasicValueEnum<'ctx>, CompileError> {
+    let Expression::UnaryOp { op, operand } = expr else {
+        return Err(CompileError::InternalError(
+            format!("Expected UnaryOp, got {:?}", expr),
+            None,
+        ));
+    };
+    let value = compiler.compile_expression(operand)?;
+    match (op, value) {
+        (UnaryOperator::Negate, BasicValueEnum::IntValue(v)) => {
+            Ok(compiler.builder.build_int_neg(v, "negtmp")?.into())
+        }
+        (UnaryOperator::Negate, BasicValueEnum::FloatValue(v)) => {
+            Ok(compiler.builder.build_float_neg(v, "fnegtmp")?.into())
+        }
+        (UnaryOperator::BitwiseNot, BasicValueEnum::IntValue(v)) => {
+            Ok(compiler.builder.build_not(v, "nottmp")?.into())
+        }
+        (UnaryOperator::Not, BasicValueEnum::IntValue(v)) if v.get_type().get_bit_width() == 1 => {
+            Ok(compiler.builder.build_not(v, "lnottmp")?.into())
+        }
+        (_, value) => Err(CompileError::TypeMismatch {
+            expected: match op {
+                UnaryOperator::Negate => "int or float".to_string(),
+                UnaryOperator::BitwiseNot => "int".to_string(),
+                UnaryOperator::Not => "bool".to_string(),
+            },
+            found: format!("{:?}", value.get_type()),
+            span: compiler.get_current_span(),
+        }),
+    }
+}
+
 pub fn compile_type_cast<'ctx>(
     compiler: &mut LLVMCompiler<'ctx>,
     expr: &Expression,
@@ -117,7 +117,7 @@ pub fn compile_pattern_match<'ctx>(
                 let arm_value = match &arm.body {
                     Expression::Block(stmts) => {
                         if stmts.is_empty() {
-                            compiler.context.i32_type().const_int(0, false).into()
+                            compiler.unit_value()
                         } else {
                             for stmt in &stmts[..stmts.len() - 1] {
                                 compiler.compile_statement(stmt)?;
@@ -127,7 +127,7 @@ pub fn compile_pattern_match<'ctx>(
                                 compiler.compile_expression(expr)?
                             } else {
                                 compiler.compile_statement(last)?;
-                                compiler.context.i32_type().const_int(0, false).into()
+                                compiler.unit_value()
                             }
                         }
                     }
@@ -136,12 +136,13 @@ pub fn compile_pattern_match<'ctx>(
                     }
                 };
 
-                if first_arm_value.is_none() {
-                    first_arm_value = Some(arm_value);
-                }
-
+                // Arms that end in `return` never reach the merge block, so only
+                // arms that fall through decide the type of the match result
                 let arm_end_block = compiler.current_block()?;
                 if arm_end_block.get_terminator().is_none() {
+                    if first_arm_value.is_none() {
+                        first_arm_value = Some(arm_value);
+                    }
                     // Coerce arm value to match the first arm's type if possible
                     let coerced_value = if let Some(first_val) = first_arm_value {
                         coerce_to_match_type(compiler, arm_value, first_val)?
@@ -207,7 +208,7 @@ pub fn compile_pattern_match<'ctx>(
                 match &then_arm.body {
                     Expression::Block(stmts) => {
                         if stmts.is_empty() {
-                            compiler.context.i32_type().const_int(0, false).into()
+                            compiler.unit_value()
                         } else {
                             for stmt in &stmts[..stmts.len() - 1] {
                                 compiler.compile_statement(stmt)?;
@@ -217,7 +218,7 @@ pub fn compile_pattern_match<'ctx>(
                                 compiler.compile_expression(expr)?
                             } else {
                                 compiler.compile_statement(last)?;
-                                compiler.context.i32_type().const_int(0, false).into()
+                                compiler.unit_value()
                             }
                         }
                     }
@@ -226,7 +227,7 @@ pub fn compile_pattern_match<'ctx>(
                     }
                 }
             } else {
-                compiler.context.i32_type().const_int(0, false).into()
+                compiler.unit_value()
             };
 
             let then_end_block = compiler.current_block()?;
@@ -241,7 +242,7 @@ pub fn compile_pattern_match<'ctx>(
                     match &else_arm.body {
                         Expression::Block(stmts) => {
                             if stmts.is_empty() {
-                                compiler.context.i32_type().const_int(0, false).into()
+                                compiler.unit_value()
                             } else {
                                 for stmt in &stmts[..stmts.len() - 1] {
                                     compiler.compile_statement(stmt)?;
@@ -251,7 +252,7 @@ pub fn compile_pattern_match<'ctx>(
                                     compiler.compile_expression(expr)?
                                 } else {
                                     compiler.compile_statement(last)?;
-                                    compiler.context.i32_type().const_int(0, false).into()
+                                    compiler.unit_value()
                                 }
                             }
                         }
@@ -260,14 +261,19 @@ pub fn compile_pattern_match<'ctx>(
                         }
                     }
                 } else {
-                    compiler.context.i32_type().const_int(0, false).into()
+                    compiler.unit_value()
                 }
             } else {
-                compiler.context.i32_type().const_int(0, false).into()
+                compiler.unit_value()
             };
 
             let else_end_block = compiler.current_block()?;
             if else_end_block.get_terminator().is_none() {
+                // A void arm facing a valued one (or vice versa) must still feed one phi type
+                let else_value = match incoming_values.first() {
+                    Some((then_value, _)) => coerce_to_match_type(compiler, else_value, *then_value)?,
+                    None => else_value,
+                };
                 incoming_values.push((else_value, else_end_block));
                 compiler.builder.build_unconditional_branch(merge_block)?;
             }
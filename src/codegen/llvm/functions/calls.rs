@@ -69,6 +69,7 @@ fn compile_and_convert_args<'ctx>(
     for (i, arg) in args.iter().enumerate() {
         let mut val = compiler.compile_expression(arg)?;
         if i < param_types.len() {
+            val = maybe_lower_repr_enum_arg(compiler, arg, val, param_types[i])?;
             val = maybe_convert_ptr_to_string_struct(compiler, val, param_types[i])?;
             val = maybe_cast_int_arg(compiler, val, param_types[i])?;
         }
@@ -80,6 +81,26 @@ fn compile_and_convert_args<'ctx>(
         .collect()
 }
 
+/// repr enums passed where an integer is expected (extern calls) lower to their discriminant.
+/// repr enums are payload-free, so the discriminant is the struct's only field
+fn maybe_lower_repr_enum_arg<'ctx>(
+    compiler: &mut LLVMCompiler<'ctx>,
+    arg: &ast::Expression,
+    val: BasicValueEnum<'ctx>,
+    expected_type: BasicMetadataTypeEnum<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, CompileError> {
+    if !val.is_struct_value() || !matches!(expected_type, BasicMetadataTypeEnum::IntType(_)) {
+        return Ok(val);
+    }
+    let arg_type = compiler.infer_expression_type(arg)?;
+    if compiler.enum_repr(&arg_type).is_none() {
+        return Ok(val);
+    }
+    Ok(compiler
+        .builder
+        .build_extract_value(val.into_struct_value(), 0, "repr_tag")?)
+}
+
 fn maybe_cast_int_arg<'ctx>(
     compiler: &mut LLVMCompiler<'ctx>,
     val: BasicValueEnum<'ctx>,
@@ -154,8 +175,15 @@ fn try_dispatch_compiler_intrinsic<'ctx>(
     func: &str,
     args: &[ast::Expression],
 ) -> Option<Result<BasicValueEnum<'ctx>, CompileError>> {
+    // An aliased import (`{ core as c } = @std`) maps the alias to core's module id
+    let is_core = module == "core"
+        || compiler.module_imports.get(module) == Some(&compiler.get_module_id("core"));
     match module {
         "compiler" | "builtin" | "@builtin" => dispatch_compiler_function(compiler, func, args),
+        // core.assert takes an optional message, which a Zen function can't express
+        _ if is_core && func == "assert" => Some(stdlib_codegen::compile_core_assert(compiler, args)),
+        // core.offset_of takes a type and a field name rather than values
+        _ if is_core && func == "offset_of" => Some(stdlib_codegen::compile_core_offset_of(compiler, args)),
         // NOTE: "io" module is now implemented in stdlib/io/io.zen using intrinsics
         // The magic dispatch has been removed - io.* functions are now real Zen functions
         _ => None,
@@ -208,6 +236,18 @@ fn dispatch_compiler_function<'ctx>(
             });
             stdlib_codegen::compile_sizeof(compiler, type_arg.as_ref())
         }
+        "hash_impl_or" => {
+            let type_arg = func.find('<').and_then(|pos| {
+                crate::parser::parse_type_from_string(&func[pos + 1..func.len() - 1]).ok()
+            });
+            stdlib_codegen::compile_hash_impl_or(compiler, args, type_arg.as_ref())
+        }
+        "eq_impl_or" => {
+            let type_arg = func.find('<').and_then(|pos| {
+                crate::parser::parse_type_from_string(&func[pos + 1..func.len() - 1]).ok()
+            });
+            stdlib_codegen::compile_eq_impl_or(compiler, args, type_arg.as_ref())
+        }
         "memset" => stdlib_codegen::compile_memset(compiler, args),
         "memcpy" => stdlib_codegen::compile_memcpy(compiler, args),
         "memmove" => stdlib_codegen::compile_memmove(compiler, args),
@@ -218,6 +258,7 @@ fn dispatch_compiler_function<'ctx>(
         "ctlz" => stdlib_codegen::compile_ctlz(compiler, args),
         "cttz" => stdlib_codegen::compile_cttz(compiler, args),
         "ctpop" => stdlib_codegen::compile_ctpop(compiler, args),
+        "is_little_endian" => stdlib_codegen::compile_is_little_endian(compiler, args),
         "syscall0" => stdlib_codegen::compile_syscall0(compiler, args),
         "syscall1" => stdlib_codegen::compile_syscall1(compiler, args),
         "syscall2" => stdlib_codegen::compile_syscall2(compiler, args),
@@ -229,6 +270,14 @@ fn dispatch_compiler_function<'ctx>(
         // IO intrinsics (libc wrappers)
         "libc_write" => stdlib_codegen::compile_libc_write(compiler, args),
         "libc_read" => stdlib_codegen::compile_libc_read(compiler, args),
+        "stdout_write" => stdlib_codegen::compile_stdout_write(compiler, args),
+        "stdout_flush" => stdlib_codegen::compile_stdout_flush(compiler, args),
+        // Float formatting
+        "format_f64" => stdlib_codegen::compile_format_f64(compiler, args),
+        "format_f64_sci" => stdlib_codegen::compile_format_f64_sci(compiler, args),
+        "format_f64_g" => stdlib_codegen::compile_format_f64_g(compiler, args),
+        // Random state
+        "random_state" => stdlib_codegen::compile_random_state(compiler, args),
         _ => return None,
     })
 }
@@ -248,18 +297,47 @@ fn try_compile_direct_call<'ctx>(
     // Check TypeContext first, then local cache
     let return_type = compiler.type_ctx.get_function_return_type(name)
         .or_else(|| compiler.function_types.get(name).cloned());
-    if let Some(return_type) = return_type {
-        track_generic_return_type(compiler, &return_type);
+    if let Some(return_type) = &return_type {
+        track_generic_return_type(compiler, return_type);
     }
     if function.get_type().get_return_type().is_none() {
-        Ok(Some(compiler.context.i32_type().const_zero().into()))
+        Ok(Some(compiler.unit_value()))
     } else {
-        Ok(Some(call.try_as_basic_value().left().ok_or_else(|| {
+        let val = call.try_as_basic_value().left().ok_or_else(|| {
             CompileError::InternalError("Function call did not return a value".to_string(), compiler.get_current_span())
-        })?))
+        })?;
+        Ok(Some(maybe_raise_repr_enum_return(compiler, return_type.as_ref(), val)?))
     }
 }
 
+/// A repr enum returned from an extern function arrives as its bare discriminant;
+/// rebuild the `{ discriminant }` struct the rest of codegen expects
+fn maybe_raise_repr_enum_return<'ctx>(
+    compiler: &mut LLVMCompiler<'ctx>,
+    return_type: Option<&AstType>,
+    val: BasicValueEnum<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, CompileError> {
+    if !val.is_int_value() {
+        return Ok(val);
+    }
+    let Some(enum_type) = return_type
+        .and_then(|t| compiler.repr_enum_info(t))
+        .map(|enum_info| enum_info.llvm_type)
+    else {
+        return Ok(val);
+    };
+    let tag = compiler.builder.build_int_z_extend_or_bit_cast(
+        val.into_int_value(),
+        compiler.context.i64_type(),
+        "repr_tag",
+    )?;
+    Ok(compiler
+        .builder
+        .build_insert_value(enum_type.get_undef(), tag, 0, "repr_enum")?
+        .into_struct_value()
+        .into())
+}
+
 fn try_compile_indirect_call<'ctx>(
     compiler: &mut LLVMCompiler<'ctx>,
     name: &str,
@@ -282,7 +360,7 @@ fn try_compile_indirect_call<'ctx>(
         track_generic_return_type(compiler, ret_type);
     }
     if function_type.get_return_type().is_none() {
-        Ok(Some(compiler.context.i32_type().const_zero().into()))
+        Ok(Some(compiler.unit_value()))
     } else {
         Ok(Some(call.try_as_basic_value().left().ok_or_else(|| {
             CompileError::InternalError("Function call did not return a value".to_string(), compiler.get_current_span())
@@ -308,6 +386,12 @@ pub fn compile_function_call<'ctx>(
             let payload = args.first().map(|a| Box::new(a.clone()));
             return compiler.compile_enum_variant(module, func, &payload);
         }
+        // math.abs(-2.5) calls the float version (fabs), matching the typechecker
+        let imports_math = compiler.module_imports.get(module) == Some(&compiler.get_module_id("math"));
+        let func = match crate::intrinsics::math_float_overload(func) {
+            Some(float_func) if imports_math && first_arg_is_float(compiler, args) => float_func,
+            _ => func,
+        };
         // Try stdlib module function: io.println -> println
         // Stdlib functions are compiled with their simple name, not qualified
         if let Some(result) = try_compile_direct_call(compiler, func, args)? {
@@ -326,6 +410,15 @@ pub fn compile_function_call<'ctx>(
     Err(CompileError::UndeclaredFunction(name.to_string(), compiler.get_current_span()))
 }
 
+fn first_arg_is_float(compiler: &LLVMCompiler<'_>, args: &[ast::Expression]) -> bool {
+    args.first().is_some_and(|arg| {
+        matches!(
+            crate::codegen::llvm::expressions::inference::infer_expression_type(compiler, arg),
+            Ok(AstType::F32 | AstType::F64)
+        )
+    })
+}
+
 // --- Cast Builtin ---
 
 fn compile_cast_builtin<'ctx>(
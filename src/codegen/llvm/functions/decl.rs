@@ -10,7 +10,11 @@ pub fn declare_external_function<'ctx>(
     compiler: &mut LLVMCompiler<'ctx>,
     ext_func: &ast::ExternalFunction,
 ) -> Result<(), CompileError> {
-    let ret_type = compiler.to_llvm_type(&ext_func.return_type)?;
+    // repr enums cross the C boundary as their bare integer representation
+    let ret_type = compiler
+        .enum_repr(&ext_func.return_type)
+        .unwrap_or_else(|| ext_func.return_type.clone());
+    let ret_type = compiler.to_llvm_type(&ret_type)?;
 
     // First get the basic types for the parameters
     let span = compiler.get_current_span();
@@ -18,7 +22,8 @@ pub fn declare_external_function<'ctx>(
         .args
         .iter()
         .map(|t| {
-            compiler.to_llvm_type(t).and_then(|t| {
+            let t = compiler.enum_repr(t).unwrap_or_else(|| t.clone());
+            compiler.to_llvm_type(&t).and_then(|t| {
                 t.into_basic_type().map_err(|e| match e {
                     CompileError::TypeMismatch { expected, found, span: None } => {
                         CompileError::TypeMismatch { expected, found, span: span.clone() }
@@ -89,16 +94,8 @@ pub fn declare_function<'ctx>(
     let actual_return_type = if function.name == "main" {
         match &function.return_type {
             AstType::Void => AstType::I32, // Convert void to i32
-            AstType::Generic { name, .. } if compiler.well_known.is_result(name) => {
-                // Main returning Result<T,E> is not supported in JIT mode
-                eprintln!(
-                    "Warning: main() returning Result<T,E> is not fully supported in JIT mode"
-                );
-                eprintln!("The function will compile but may crash when executed.");
-                eprintln!("Consider using 'void' or 'i32' as the return type for main()");
-                // For now, keep the Result type and hope for the best
-                function.return_type.clone()
-            }
+            // main returning Result<T,E> keeps its type; both the JIT runner and native
+            // builds call it through a wrapper that turns the discriminant into the exit code
             _ => function.return_type.clone(),
         }
     } else {
@@ -137,7 +134,12 @@ pub fn declare_function<'ctx>(
 
     let param_metadata = param_metadata?;
 
-    // Create the function type with the metadata types
+    // Structs are returned as first-class aggregates rather than through a hidden
+    // sret pointer. When one doesn't fit in the return registers, the LLVM backend
+    // demotes the return to a hidden pointer itself, identically for the JIT and
+    // object emission, so Zen-to-Zen calls always agree. Lowering sret here would
+    // change the LLVM signature seen by every call site, function pointer type and
+    // return path (`?`, `.raise()`) for no behavioral gain.
     let function_type = match return_type {
         Type::Basic(b) => match b {
             BasicTypeEnum::ArrayType(t) => t.fn_type(&param_metadata, false),
@@ -223,6 +225,7 @@ pub fn compile_function_body<'ctx>(
     let entry_block = compiler.context.append_basic_block(function_value, "entry");
     compiler.builder.position_at_end(entry_block);
     compiler.current_function = Some(function_value);
+    compiler.build_coverage_counter(&function.name)?;
 
     // Clear variables from previous function by entering a new scope
     compiler.symbols.enter_scope();
@@ -305,6 +308,10 @@ pub fn compile_function_body<'ctx>(
                     }
 
                     if let Some(expected_ret_type) = function_value.get_type().get_return_type() {
+                        // A void tail (arms that only ran statements) becomes the type's zero
+                        if compiler.is_unit_value(value) {
+                            value = compiler.cast_value_to_type(value, expected_ret_type)?;
+                        }
                         let actual_type = value.get_type();
                         if actual_type != expected_ret_type {
                             // If types don't match and expected is struct but actual is i32,
@@ -353,7 +353,14 @@ impl<'ctx> LLVMCompiler<'ctx> {
                             _ => ("%d", val.into()),
                         }
                     } else if val.is_float_value() {
-                        ("%.6f", val.into())
+                        // Varargs take doubles only: promote f32 like C's default promotions
+                        let float_val = val.into_float_value();
+                        let double_val = if float_val.get_type() == self.context.f64_type() {
+                            float_val
+                        } else {
+                            self.builder.build_float_ext(float_val, self.context.f64_type(), "f32_to_f64")?
+                        };
+                        ("%.6f", double_val.into())
                     } else if val.is_pointer_value() {
                         // Pointer values are strings - use %s
                         ("%s", val.into())
@@ -18,6 +18,7 @@ use std::collections::HashMap;
 mod behaviors;
 mod binary_ops;
 mod builtins;
+mod coverage;
 mod expressions;
 mod functions;
 mod generics;
@@ -58,6 +59,10 @@ impl<'ctx> Type<'ctx> {
 pub struct StructTypeInfo<'ctx> {
     pub llvm_type: StructType<'ctx>,
     pub fields: HashMap<String, (usize, AstType)>,
+    /// Bitfields: name -> (bit offset, bit width) within the storage unit at the field's index
+    pub bitfields: HashMap<String, (u32, u32)>,
+    /// All fields share the storage at index 0
+    pub is_union: bool,
 }
 
 // Variable information with mutability tracking
@@ -96,6 +101,14 @@ pub struct LLVMCompiler<'ctx> {
     pub well_known: WellKnownTypes,
     /// Type context from typechecker - use this for type lookups instead of re-inferring
     pub type_ctx: TypeContext,
+    /// Emit runtime safety checks (null dereference guards); off for release builds
+    pub debug_checks: bool,
+    /// Count calls of every function and report them when main returns
+    pub coverage: bool,
+    /// Call counter global of each instrumented function, in compilation order
+    pub coverage_counters: Vec<(String, inkwell::values::GlobalValue<'ctx>)>,
+    /// Layout of the module's target, where a type's real byte size matters
+    pub target_data: inkwell::targets::TargetData,
 }
 
 impl<'ctx> LLVMCompiler<'ctx> {
@@ -235,6 +248,8 @@ impl<'ctx> LLVMCompiler<'ctx> {
 
     pub fn new(context: &'ctx Context, type_ctx: TypeContext) -> Self {
         let module = context.create_module("main");
+        types::set_native_target(&module);
+        let target_data = types::module_target_data(&module);
         let builder = context.create_builder();
         let mut symbols = symbols::SymbolTable::new();
         let comptime_evaluator = comptime::ComptimeInterpreter::new();
@@ -278,6 +293,10 @@ impl<'ctx> LLVMCompiler<'ctx> {
             current_span: None,
             well_known: WellKnownTypes::new(),
             type_ctx,
+            debug_checks: true,
+            coverage: false,
+            coverage_counters: Vec::new(),
+            target_data,
         };
 
         // Auto-inject built-in modules (always available without explicit import)
@@ -622,6 +641,11 @@ impl<'ctx> LLVMCompiler<'ctx> {
         }
 
         // Second pass: Define and compile all functions (skip generic functions)
+        // Bodies are compiled one at a time on purpose. They share whole-program state
+        // (symbols, generic_type_context, the one Module), and inkwell's Context is not
+        // Send, so splitting them across threads would take a context and module per
+        // thread plus a merge with Module::link_in_module, after first moving that shared
+        // state out of LLVMCompiler. Until then codegen stays single-threaded.
         for declaration in &program.declarations {
             if let ast::Declaration::Function(func) = declaration {
                 if func.type_params.is_empty() {
@@ -630,9 +654,25 @@ impl<'ctx> LLVMCompiler<'ctx> {
             }
         }
 
+        self.build_coverage_report()?;
+
         Ok(())
     }
 
+    /// The value of a void expression (`()`, `void`, or a call to a void function).
+    ///
+    /// Expressions always produce a `BasicValueEnum`, so void ones produce the
+    /// empty struct `{}`: it occupies no storage and can't pass for a number.
+    /// Void functions end in `ret void` and statement position simply drops it.
+    pub fn unit_value(&self) -> BasicValueEnum<'ctx> {
+        self.context.struct_type(&[], false).const_zero().into()
+    }
+
+    /// Whether `value` is the unit value of a void expression
+    pub fn is_unit_value(&self, value: BasicValueEnum<'ctx>) -> bool {
+        matches!(value, BasicValueEnum::StructValue(v) if v.get_type().count_fields() == 0)
+    }
+
     pub fn cast_value_to_type(
         &self,
         value: BasicValueEnum<'ctx>,
@@ -643,6 +683,20 @@ impl<'ctx> LLVMCompiler<'ctx> {
             return Ok(value);
         }
 
+        // A void tail where a value is expected (e.g. a match whose arms only ran
+        // statements) has nothing to convert, so it becomes the target's zero
+        if self.is_unit_value(value) {
+            return Ok(match target_type {
+                BasicTypeEnum::IntType(t) => t.const_zero().into(),
+                BasicTypeEnum::FloatType(t) => t.const_zero().into(),
+                BasicTypeEnum::PointerType(t) => t.const_null().into(),
+                BasicTypeEnum::StructType(t) => t.const_zero().into(),
+                BasicTypeEnum::ArrayType(t) => t.const_zero().into(),
+                BasicTypeEnum::VectorType(t) => t.const_zero().into(),
+                BasicTypeEnum::ScalableVectorType(t) => t.const_zero().into(),
+            });
+        }
+
         // Handle casting between integer types
         if let (BasicValueEnum::IntValue(int_val), BasicTypeEnum::IntType(target_int_type)) =
             (value, target_type)
@@ -110,12 +110,24 @@ impl<'ctx> LLVMCompiler<'ctx> {
 
     /// Compile literal pattern matching
     fn compile_literal_pattern(
-        &self,
+        &mut self,
         scrutinee: &BasicValueEnum<'ctx>,
         expr: &ast::Expression,
     ) -> Result<(IntValue<'ctx>, Vec<(String, BasicValueEnum<'ctx>)>), CompileError> {
         let false_val = self.context.bool_type().const_int(0, false);
 
+        // String literals compare by content against a string scrutinee
+        if let (BasicValueEnum::PointerValue(_), ast::Expression::String(_)) = (scrutinee, expr) {
+            let literal = self.compile_expression(expr)?;
+            let matches = self.compile_string_compare(
+                *scrutinee,
+                literal,
+                inkwell::IntPredicate::EQ,
+                "str_match",
+            )?;
+            return Ok((matches.into_int_value(), vec![]));
+        }
+
         let BasicValueEnum::IntValue(scrutinee_int) = scrutinee else {
             return Ok((false_val, vec![]));
         };
@@ -130,12 +142,13 @@ impl<'ctx> LLVMCompiler<'ctx> {
                 };
                 self.compare_int_literal(*scrutinee_int, literal_val, "bool_match")
             }
+            // Integer literals take the scrutinee's width, so `x: i64 ? | 1 { }` compares i64s
             ast::Expression::Integer32(n) => {
-                let literal_val = self.context.i32_type().const_int(*n as u64, false);
+                let literal_val = scrutinee_int.get_type().const_int(*n as u64, true);
                 self.compare_int_literal(*scrutinee_int, literal_val, "i32_match")
             }
             ast::Expression::Integer64(n) => {
-                let literal_val = self.context.i64_type().const_int(*n as u64, false);
+                let literal_val = scrutinee_int.get_type().const_int(*n as u64, true);
                 self.compare_int_literal(*scrutinee_int, literal_val, "i64_match")
             }
             _ => Err(CompileError::UnsupportedFeature(
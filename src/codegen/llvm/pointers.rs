@@ -1,9 +1,9 @@
 use super::LLVMCompiler;
-use crate::ast::Expression;
+use crate::ast::{AstType, Expression};
 use crate::error::CompileError;
 use inkwell::{
     types::BasicType,
-    values::{BasicValue, BasicValueEnum},
+    values::{BasicValue, BasicValueEnum, PointerValue},
 };
 
 impl<'ctx> LLVMCompiler<'ctx> {
@@ -76,6 +76,7 @@ impl<'ctx> LLVMCompiler<'ctx> {
                     let ptr_type = self.context.ptr_type(inkwell::AddressSpace::default());
                     let ptr_value = self.builder.build_load(ptr_type, alloca, "load_ptr")?;
                     let ptr = ptr_value.into_pointer_value();
+                    self.build_null_check(ptr)?;
 
                     // 2. Load the value from the address stored in the pointer
                     let llvm_type = self.to_llvm_type(inner)?;
@@ -157,6 +158,7 @@ impl<'ctx> LLVMCompiler<'ctx> {
             });
         }
         let ptr = ptr_val.into_pointer_value();
+        self.build_null_check(ptr)?;
 
         // Since we don't have type info, assume i32 for now (most common in tests)
         let llvm_type = super::Type::Basic(self.context.i32_type().as_basic_type_enum());
@@ -174,6 +176,76 @@ impl<'ctx> LLVMCompiler<'ctx> {
         }
     }
 
+    /// Lower built-in methods on raw/mutable pointers; `None` if `method` isn't one of them
+    pub fn try_compile_pointer_method(
+        &mut self,
+        object: &Expression,
+        method: &str,
+        args: &[Expression],
+    ) -> Result<Option<BasicValueEnum<'ctx>>, CompileError> {
+        if !matches!((method, args.len()), ("offset", 1) | ("is_null", 0) | ("is_not_null", 0)) {
+            return Ok(None);
+        }
+        match self.infer_expression_type(object) {
+            Ok(t) if t.is_mutable_ptr() || t.is_raw_ptr() => {}
+            _ => return Ok(None),
+        }
+
+        if method == "offset" {
+            return self.compile_pointer_offset(object, &args[0]).map(Some);
+        }
+        let ptr_val = self.compile_expression(object)?;
+        if !ptr_val.is_pointer_value() {
+            return Err(CompileError::TypeMismatch {
+                expected: "pointer".to_string(),
+                found: format!("{:?}", ptr_val.get_type()),
+                span: self.current_span.clone(),
+            });
+        }
+        let ptr = ptr_val.into_pointer_value();
+        let result = if method == "is_null" {
+            self.builder.build_is_null(ptr, "is_null")?
+        } else {
+            self.builder.build_is_not_null(ptr, "is_not_null")?
+        };
+        Ok(Some(result.as_basic_value_enum()))
+    }
+
+    /// In debug builds, panic with `null pointer dereference` before loading through `ptr`
+    fn build_null_check(&mut self, ptr: PointerValue<'ctx>) -> Result<(), CompileError> {
+        if !self.debug_checks {
+            return Ok(());
+        }
+        let function = self.current_function.ok_or_else(|| {
+            CompileError::InternalError(
+                "Pointer dereference outside of a function".to_string(),
+                self.get_current_span(),
+            )
+        })?;
+        let null_bb = self.context.append_basic_block(function, "deref_null");
+        let ok_bb = self.context.append_basic_block(function, "deref_ok");
+
+        let is_null = self.builder.build_is_null(ptr, "is_null")?;
+        self.builder.build_conditional_branch(is_null, null_bb, ok_bb)?;
+
+        self.builder.position_at_end(null_bb);
+        let message = self
+            .builder
+            .build_global_string_ptr("null pointer dereference", "null_deref_msg")?;
+        super::stdlib_codegen::build_panic(self, message.as_pointer_value())?;
+
+        self.builder.position_at_end(ok_bb);
+        Ok(())
+    }
+
+    /// `null` is an opaque null pointer; its pointee type comes from where it is stored
+    pub fn compile_null_pointer(&self) -> BasicValueEnum<'ctx> {
+        self.context
+            .ptr_type(inkwell::AddressSpace::default())
+            .const_null()
+            .as_basic_value_enum()
+    }
+
     pub fn compile_pointer_to_int(
         &mut self,
         expr: &Expression,
@@ -202,6 +274,7 @@ impl<'ctx> LLVMCompiler<'ctx> {
         pointer: &Expression,
         offset: &Expression,
     ) -> Result<BasicValueEnum<'ctx>, CompileError> {
+        let pointer_type = self.infer_expression_type(pointer)?;
         let base_val = self.compile_expression(pointer)?;
         let offset_val = self.compile_expression(offset)?;
         if !base_val.is_pointer_value() {
@@ -218,19 +291,24 @@ impl<'ctx> LLVMCompiler<'ctx> {
                 span: self.current_span.clone(),
             });
         }
-        unsafe {
-            let ptr_type = base_val.get_type();
-            let _offset = offset_val.into_int_value();
-            let ptr = base_val.into_pointer_value();
-            Ok(self
-                .builder
-                .build_gep(
-                    ptr_type,
-                    ptr,
-                    &[self.context.i32_type().const_int(0, false)],
-                    "gep_tmp",
-                )?
-                .into())
-        }
+
+        // Offsets count elements of the pointee type; untyped pointers step in bytes
+        let element_type = match pointer_type.ptr_inner() {
+            Some(inner) if !matches!(inner, AstType::U8 | AstType::Void) => {
+                let llvm_type = self.to_llvm_type(inner)?;
+                self.expect_basic_type(llvm_type)?
+            }
+            _ => self.context.i8_type().as_basic_type_enum(),
+        };
+        let offset = self.builder.build_int_s_extend_or_bit_cast(
+            offset_val.into_int_value(),
+            self.context.i64_type(),
+            "offset_i64",
+        )?;
+        let ptr = unsafe {
+            self.builder
+                .build_gep(element_type, base_val.into_pointer_value(), &[offset], "ptr_offset")?
+        };
+        Ok(ptr.as_basic_value_enum())
     }
 }
@@ -12,6 +12,15 @@ pub fn compile_return<'ctx>(
     // Execute all deferred expressions before returning
     compiler.execute_deferred_expressions()?;
 
+    // `return void_call()` and `return ()` in a void function carry no value
+    let is_void_function = compiler
+        .current_function
+        .is_some_and(|func| func.get_type().get_return_type().is_none());
+    if is_void_function {
+        compiler.builder.build_return(None)?;
+        return Ok(());
+    }
+
     // Cast return value to match function return type using shared helper
     let final_value = if let Some(func) = compiler.current_function {
         if let Some(expected_ret_type) = func.get_type().get_return_type() {
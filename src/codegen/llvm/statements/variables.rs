@@ -554,6 +554,12 @@ pub fn compile_assignment<'ctx>(
                     ))
                 }
             } else if let Expression::MemberAccess { object, member } = pointer {
+                // name.field = value on a struct (or pointer-to-struct) variable
+                if let Expression::Identifier(name) = &**object {
+                    if compiler.compile_identifier_field_assignment(name, member, value)? {
+                        return Ok(());
+                    }
+                }
                 // ptr.val.field = value: store value at field within dereferenced struct
                 if let Expression::PointerDereference(ptr_expr) = &**object {
                     let ptr_val = compiler.compile_expression(ptr_expr)?;
@@ -581,7 +587,9 @@ pub fn compile_assignment<'ctx>(
                                         &format!("{}_field_ptr", member),
                                     )?;
                                     let val = compiler.compile_expression(value)?;
-                                    compiler.builder.build_store(field_ptr, val)?;
+                                    if !compiler.store_bitfield(&struct_name, member, field_ptr, val)? {
+                                        compiler.builder.build_store(field_ptr, val)?;
+                                    }
                                     return Ok(());
                                 }
                             }
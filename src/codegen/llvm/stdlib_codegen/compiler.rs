@@ -178,7 +178,7 @@ pub fn compile_raw_deallocate<'ctx>(
     let _size = compiler.compile_expression(&args[1])?;
     let free = get_or_declare_fn(compiler, "free", None, &[ptr_type(compiler).into()]);
     compiler.builder.build_call(free, &[ptr.into()], "")?;
-    Ok(compiler.context.i32_type().const_zero().into())
+    Ok(compiler.unit_value())
 }
 
 pub fn compile_raw_reallocate<'ctx>(
@@ -325,7 +325,7 @@ pub fn compile_set_discriminant<'ctx>(
     let ptr = compiler.compile_expression(&args[0])?.into_pointer_value();
     let disc = compiler.compile_expression(&args[1])?;
     compiler.builder.build_store(ptr, disc)?;
-    Ok(compiler.context.i32_type().const_zero().into())
+    Ok(compiler.unit_value())
 }
 
 pub fn compile_get_payload<'ctx>(
@@ -348,7 +348,7 @@ pub fn compile_set_payload<'ctx>(
     let _ptr = compiler.compile_expression(&args[0])?;
     let _payload = compiler.compile_expression(&args[1])?;
     // TODO: needs size information for proper copy
-    Ok(compiler.context.i32_type().const_zero().into())
+    Ok(compiler.unit_value())
 }
 
 // =============================================================================
@@ -409,7 +409,7 @@ pub fn compile_store<'ctx>(
     let ptr = compiler.compile_expression(&args[0])?.into_pointer_value();
     let val = compiler.compile_expression(&args[1])?;
     compiler.builder.build_store(ptr, val)?;
-    Ok(compiler.context.i32_type().const_zero().into())
+    Ok(compiler.unit_value())
 }
 
 // =============================================================================
@@ -420,6 +420,21 @@ pub fn compile_sizeof<'ctx>(
     compiler: &mut LLVMCompiler<'ctx>,
     type_arg: Option<&AstType>,
 ) -> Result<BasicValueEnum<'ctx>, CompileError> {
+    // Packed structs and unions are sized by their LLVM layout, since neither is
+    // a plain sequence of fields; every other struct keeps the field-size sum below
+    let struct_name = match type_arg {
+        Some(AstType::Struct { name, .. }) => Some(name),
+        Some(AstType::Generic { name, type_args }) if type_args.is_empty() => Some(name),
+        _ => None,
+    };
+    if let Some(size) = struct_name
+        .and_then(|name| compiler.struct_types.get(name))
+        .filter(|info| info.llvm_type.is_packed() || info.is_union)
+        .and_then(|info| info.llvm_type.size_of())
+    {
+        return Ok(size.into());
+    }
+
     let size: u64 = match type_arg {
         Some(ty) => match ty {
             AstType::I8 | AstType::U8 | AstType::Bool => 1,
@@ -441,6 +456,85 @@ pub fn compile_sizeof<'ctx>(
     Ok(compiler.context.i64_type().const_int(size, false).into())
 }
 
+// =============================================================================
+// Behavior Dispatch
+// =============================================================================
+
+/// Look up T's implementation of a behavior method, if T has one
+fn behavior_method<'ctx>(
+    compiler: &LLVMCompiler<'ctx>,
+    type_arg: Option<&AstType>,
+    method: &str,
+) -> Option<inkwell::values::FunctionValue<'ctx>> {
+    let type_name = match type_arg {
+        Some(AstType::Struct { name, .. })
+        | Some(AstType::Enum { name, .. })
+        | Some(AstType::Generic { name, .. }) => name.as_str(),
+        _ => return None,
+    };
+    compiler
+        .behavior_codegen
+        .as_ref()
+        .and_then(|bc| bc.resolve_method(type_name, method))
+}
+
+/// Trait methods receive struct arguments by pointer
+fn behavior_arg<'ctx>(
+    compiler: &mut LLVMCompiler<'ctx>,
+    expr: &ast::Expression,
+) -> Result<inkwell::values::BasicMetadataValueEnum<'ctx>, CompileError> {
+    let value = compiler.compile_expression(expr)?;
+    if value.is_struct_value() {
+        let slot = compiler.builder.build_alloca(value.get_type(), "behavior_arg")?;
+        compiler.builder.build_store(slot, value)?;
+        Ok(slot.into())
+    } else {
+        Ok(value.into())
+    }
+}
+
+/// hash_impl_or<T>(key, default) -> u64
+/// Calls T's Hashable `hash` implementation when T has one, otherwise
+/// evaluates and returns `default` (e.g. a byte-wise hash of the key).
+pub fn compile_hash_impl_or<'ctx>(
+    compiler: &mut LLVMCompiler<'ctx>,
+    args: &[ast::Expression],
+    type_arg: Option<&AstType>,
+) -> Result<BasicValueEnum<'ctx>, CompileError> {
+    require_args(args, 2, "hash_impl_or", compiler.get_current_span())?;
+
+    let Some(hash_fn) = behavior_method(compiler, type_arg, "hash") else {
+        // No Hashable impl (primitive or plain struct key)
+        let default = compiler.compile_expression(&args[1])?;
+        return Ok(to_i64(compiler, default, false)?.into());
+    };
+
+    let key = behavior_arg(compiler, &args[0])?;
+    let call = compiler.builder.build_call(hash_fn, &[key], "user_hash")?;
+    let result = extract_call_result(call, "hash", compiler)?;
+    Ok(to_i64(compiler, result, false)?.into())
+}
+
+/// eq_impl_or<T>(a, b, default) -> bool
+/// Calls T's Comparable `equals` implementation when T has one, otherwise
+/// evaluates and returns `default` (e.g. a byte-wise comparison).
+pub fn compile_eq_impl_or<'ctx>(
+    compiler: &mut LLVMCompiler<'ctx>,
+    args: &[ast::Expression],
+    type_arg: Option<&AstType>,
+) -> Result<BasicValueEnum<'ctx>, CompileError> {
+    require_args(args, 3, "eq_impl_or", compiler.get_current_span())?;
+
+    let Some(equals_fn) = behavior_method(compiler, type_arg, "equals") else {
+        return compiler.compile_expression(&args[2]);
+    };
+
+    let lhs = behavior_arg(compiler, &args[0])?;
+    let rhs = behavior_arg(compiler, &args[1])?;
+    let call = compiler.builder.build_call(equals_fn, &[lhs, rhs], "user_equals")?;
+    extract_call_result(call, "equals", compiler)
+}
+
 // =============================================================================
 // Memory Operations (libc)
 // =============================================================================
@@ -458,7 +552,7 @@ pub fn compile_memset<'ctx>(
     let memset = get_or_declare_fn(compiler, "memset", Some(ptr_type(compiler).into()),
         &[ptr_type(compiler).into(), compiler.context.i8_type().into(), compiler.context.i64_type().into()]);
     compiler.builder.build_call(memset, &[dest.into(), val.into(), size.into()], "")?;
-    Ok(compiler.context.i32_type().const_zero().into())
+    Ok(compiler.unit_value())
 }
 
 pub fn compile_memcpy<'ctx>(
@@ -473,7 +567,7 @@ pub fn compile_memcpy<'ctx>(
     let memcpy = get_or_declare_fn(compiler, "memcpy", Some(ptr_type(compiler).into()),
         &[ptr_type(compiler).into(), ptr_type(compiler).into(), compiler.context.i64_type().into()]);
     compiler.builder.build_call(memcpy, &[dest.into(), src.into(), size.into()], "")?;
-    Ok(compiler.context.i32_type().const_zero().into())
+    Ok(compiler.unit_value())
 }
 
 pub fn compile_memmove<'ctx>(
@@ -488,7 +582,7 @@ pub fn compile_memmove<'ctx>(
     let memmove = get_or_declare_fn(compiler, "memmove", Some(ptr_type(compiler).into()),
         &[ptr_type(compiler).into(), ptr_type(compiler).into(), compiler.context.i64_type().into()]);
     compiler.builder.build_call(memmove, &[dest.into(), src.into(), size.into()], "")?;
-    Ok(compiler.context.i32_type().const_zero().into())
+    Ok(compiler.unit_value())
 }
 
 pub fn compile_memcmp<'ctx>(
@@ -569,6 +663,13 @@ pub fn compile_ctpop<'ctx>(compiler: &mut LLVMCompiler<'ctx>, args: &[ast::Expre
     compile_bit_count(compiler, args, "ctpop", false)
 }
 
+/// Compile-time constant: true when the target is little-endian.
+/// Codegen always targets the host triple, so the host endianness applies.
+pub fn compile_is_little_endian<'ctx>(compiler: &mut LLVMCompiler<'ctx>, args: &[ast::Expression]) -> Result<BasicValueEnum<'ctx>, CompileError> {
+    require_args(args, 0, "is_little_endian", compiler.get_current_span())?;
+    Ok(compiler.context.bool_type().const_int(cfg!(target_endian = "little") as u64, false).into())
+}
+
 // =============================================================================
 // Panic Intrinsic
 // =============================================================================
@@ -584,7 +685,17 @@ pub fn compile_panic<'ctx>(
     // Get the message string
     let msg_val = compiler.compile_expression(&args[0])?;
     let msg_ptr = extract_string_ptr(compiler, msg_val)?;
+    build_panic(compiler, msg_ptr)?;
+
+    // Return a dummy value (will never be reached)
+    Ok(compiler.unit_value())
+}
 
+/// Emit `panic: <message>` to stderr followed by abort(); terminates the current block
+pub fn build_panic<'ctx>(
+    compiler: &mut LLVMCompiler<'ctx>,
+    msg_ptr: PointerValue<'ctx>,
+) -> Result<(), CompileError> {
     // Get string length - we need to walk until null terminator or use a reasonable max
     // For simplicity, use fputs which handles null-terminated strings
 
@@ -599,11 +710,7 @@ pub fn compile_panic<'ctx>(
         &[ptr_type(compiler).into(), ptr_type(compiler).into()],
     );
 
-    // Declare stderr (extern FILE *stderr)
-    let stderr_global = compiler.module.get_global("stderr").unwrap_or_else(|| {
-        compiler.module.add_global(ptr_type(compiler), None, "stderr")
-    });
-    let stderr_ptr = compiler.builder.build_load(ptr_type(compiler), stderr_global.as_pointer_value(), "stderr")?;
+    let stderr_ptr = load_stderr_stream(compiler)?;
 
     // Print "panic: " prefix
     let prefix = compiler.builder.build_global_string_ptr("panic: ", "panic_prefix")?;
@@ -616,16 +723,110 @@ pub fn compile_panic<'ctx>(
     let newline = compiler.builder.build_global_string_ptr("\n", "newline")?;
     compiler.builder.build_call(fputs, &[newline.as_pointer_value().into(), stderr_ptr.into()], "")?;
 
+    // abort() skips exit handlers, so flush buffered stdout (fflush(NULL)) first
+    let fflush = get_or_declare_fn(
+        compiler,
+        "fflush",
+        Some(compiler.context.i32_type().into()),
+        &[ptr_type(compiler).into()],
+    );
+    compiler.builder.build_call(fflush, &[ptr_type(compiler).const_null().into()], "")?;
+
     // Call abort() to terminate
     let abort = get_or_declare_fn(compiler, "abort", None, &[]);
     compiler.builder.build_call(abort, &[], "")?;
 
-    // This is unreachable, but we need to return something
     // Mark as unreachable for LLVM optimization
     compiler.builder.build_unreachable()?;
+    Ok(())
+}
 
-    // Return a dummy value (will never be reached)
-    Ok(compiler.context.i32_type().const_zero().into())
+// =============================================================================
+// Core Layout Queries
+// =============================================================================
+
+/// core.offset_of(Struct, field) -> usize
+/// Byte offset of a named field within a struct, resolved at compile time from
+/// the registered struct layout (honours padding and `packed`).
+pub fn compile_core_offset_of<'ctx>(
+    compiler: &mut LLVMCompiler<'ctx>,
+    args: &[ast::Expression],
+) -> Result<BasicValueEnum<'ctx>, CompileError> {
+    let span = compiler.get_current_span();
+    let (struct_name, field_name) = crate::intrinsics::offset_of_operands(args)
+        .map_err(|msg| CompileError::TypeError(msg, span.clone()))?;
+    let info = compiler.struct_types.get(struct_name).ok_or_else(|| {
+        CompileError::TypeError(format!("core.offset_of(): unknown struct '{}'", struct_name), span.clone())
+    })?;
+    let (index, _) = info.fields.get(field_name).ok_or_else(|| {
+        CompileError::TypeError(
+            format!("core.offset_of(): struct '{}' has no field '{}'", struct_name, field_name),
+            span.clone(),
+        )
+    })?;
+    let (llvm_type, index) = (info.llvm_type, *index as u32);
+
+    // GEP from null folds to a constant: the field's byte offset
+    let base = ptr_type(compiler).const_null();
+    let field_ptr = compiler.builder.build_struct_gep(llvm_type, base, index, "field_ptr")?;
+    let offset = compiler.builder.build_ptr_to_int(field_ptr, compiler.context.i64_type(), "offset_of")?;
+    Ok(offset.into())
+}
+
+// =============================================================================
+// Core Assert
+// =============================================================================
+
+/// core.assert(condition) or core.assert(condition, message).
+/// A false condition prints the message (if given) to stderr, then exit(1).
+pub fn compile_core_assert<'ctx>(
+    compiler: &mut LLVMCompiler<'ctx>,
+    args: &[ast::Expression],
+) -> Result<BasicValueEnum<'ctx>, CompileError> {
+    let span = compiler.get_current_span();
+    if args.is_empty() || args.len() > 2 {
+        return Err(CompileError::TypeError(
+            format!("core.assert expects 1 or 2 args, got {}", args.len()),
+            span,
+        ));
+    }
+
+    let condition = match compiler.compile_expression(&args[0])? {
+        BasicValueEnum::IntValue(v) if v.get_type().get_bit_width() == 1 => v,
+        _ => return Err(CompileError::TypeError("core.assert condition must be a bool".to_string(), span)),
+    };
+    let message = match args.get(1) {
+        Some(arg) => {
+            let value = compiler.compile_expression(arg)?;
+            Some(extract_string_ptr(compiler, value)?)
+        }
+        None => None,
+    };
+
+    let current_fn = compiler.current_fn()?;
+    let fail_block = compiler.context.append_basic_block(current_fn, "assert_fail");
+    let ok_block = compiler.context.append_basic_block(current_fn, "assert_ok");
+    compiler.builder.build_conditional_branch(condition, ok_block, fail_block)?;
+
+    compiler.builder.position_at_end(fail_block);
+    if let Some(msg_ptr) = message {
+        let fputs = get_or_declare_fn(
+            compiler,
+            "fputs",
+            Some(compiler.context.i32_type().into()),
+            &[ptr_type(compiler).into(), ptr_type(compiler).into()],
+        );
+        let stderr_ptr = load_stderr_stream(compiler)?;
+        compiler.builder.build_call(fputs, &[msg_ptr.into(), stderr_ptr.into()], "")?;
+        let newline = compiler.builder.build_global_string_ptr("\n", "newline")?;
+        compiler.builder.build_call(fputs, &[newline.as_pointer_value().into(), stderr_ptr.into()], "")?;
+    }
+    let exit = get_or_declare_fn(compiler, "exit", None, &[compiler.context.i32_type().into()]);
+    compiler.builder.build_call(exit, &[compiler.context.i32_type().const_int(1, false).into()], "")?;
+    compiler.builder.build_unreachable()?;
+
+    compiler.builder.position_at_end(ok_block);
+    Ok(compiler.unit_value())
 }
 
 // =============================================================================
@@ -727,7 +928,7 @@ pub fn compile_inline_c<'ctx>(
             CompileError::InternalError(format!("Failed to link inline C module: {:?}", e), span)
         })?;
 
-    Ok(compiler.context.i32_type().const_zero().into())
+    Ok(compiler.unit_value())
 }
 
 pub fn compile_call_external<'ctx>(
@@ -1020,3 +1221,202 @@ pub fn compile_libc_read<'ctx>(
 
     extract_call_result(result, "read", compiler)
 }
+
+/// Load libc's `stdout` stream (extern FILE *stdout)
+fn load_stdout_stream<'ctx>(compiler: &mut LLVMCompiler<'ctx>) -> Result<PointerValue<'ctx>, CompileError> {
+    let stdout_global = compiler.module.get_global("stdout").unwrap_or_else(|| {
+        compiler.module.add_global(ptr_type(compiler), None, "stdout")
+    });
+    Ok(compiler
+        .builder
+        .build_load(ptr_type(compiler), stdout_global.as_pointer_value(), "stdout")?
+        .into_pointer_value())
+}
+
+/// Load libc's `stderr` stream (extern FILE *stderr)
+fn load_stderr_stream<'ctx>(compiler: &mut LLVMCompiler<'ctx>) -> Result<PointerValue<'ctx>, CompileError> {
+    let stderr_global = compiler.module.get_global("stderr").unwrap_or_else(|| {
+        compiler.module.add_global(ptr_type(compiler), None, "stderr")
+    });
+    Ok(compiler
+        .builder
+        .build_load(ptr_type(compiler), stderr_global.as_pointer_value(), "stderr")?
+        .into_pointer_value())
+}
+
+/// Buffered write to stdout: fwrite(buf, 1, len, stdout) -> bytes written
+pub fn compile_stdout_write<'ctx>(
+    compiler: &mut LLVMCompiler<'ctx>,
+    args: &[ast::Expression],
+) -> Result<BasicValueEnum<'ctx>, CompileError> {
+    require_args(args, 2, "stdout_write", compiler.get_current_span())?;
+
+    let buf_val = compiler.compile_expression(&args[0])?;
+    let len_val = compiler.compile_expression(&args[1])?;
+
+    let i64_type = compiler.context.i64_type();
+    let fwrite_fn = get_or_declare_fn(
+        compiler,
+        "fwrite",
+        Some(i64_type.into()),
+        &[ptr_type(compiler).into(), i64_type.into(), i64_type.into(), ptr_type(compiler).into()],
+    );
+
+    let buf = if buf_val.is_pointer_value() {
+        buf_val.into_pointer_value()
+    } else {
+        return Err(CompileError::TypeError(
+            "stdout_write: buf must be a pointer".to_string(),
+            compiler.get_current_span(),
+        ));
+    };
+    let len = to_i64(compiler, len_val, false)?;
+    let stream = load_stdout_stream(compiler)?;
+
+    let result = compiler.builder.build_call(
+        fwrite_fn,
+        &[buf.into(), i64_type.const_int(1, false).into(), len.into(), stream.into()],
+        "fwrite_result",
+    )?;
+
+    extract_call_result(result, "fwrite", compiler)
+}
+
+/// Write out everything buffered by stdout_write: fflush(stdout) -> 0 on success
+pub fn compile_stdout_flush<'ctx>(
+    compiler: &mut LLVMCompiler<'ctx>,
+    args: &[ast::Expression],
+) -> Result<BasicValueEnum<'ctx>, CompileError> {
+    require_args(args, 0, "stdout_flush", compiler.get_current_span())?;
+
+    let fflush_fn = get_or_declare_fn(
+        compiler,
+        "fflush",
+        Some(compiler.context.i32_type().into()),
+        &[ptr_type(compiler).into()],
+    );
+    let stream = load_stdout_stream(compiler)?;
+    let result = compiler.builder.build_call(fflush_fn, &[stream.into()], "fflush_result")?;
+
+    extract_call_result(result, "fflush", compiler)
+}
+
+// =============================================================================
+// Float Formatting (libc snprintf)
+// =============================================================================
+
+/// snprintf(buf, cap, fmt, precision, value) with a `%.*<conv>` format.
+/// Returns the number of bytes written (excluding the null terminator),
+/// clamped to `cap - 1` so callers can pass it straight to `libc_write`.
+fn compile_format_float<'ctx>(
+    compiler: &mut LLVMCompiler<'ctx>,
+    args: &[ast::Expression],
+    name: &str,
+    format: &str,
+) -> Result<BasicValueEnum<'ctx>, CompileError> {
+    let span = compiler.get_current_span();
+    require_args(args, 4, name, span.clone())?;
+
+    let buf_val = compiler.compile_expression(&args[0])?;
+    let cap_val = compiler.compile_expression(&args[1])?;
+    let value_val = compiler.compile_expression(&args[2])?;
+    let prec_val = compiler.compile_expression(&args[3])?;
+
+    let i32_type = compiler.context.i32_type();
+    let i64_type = compiler.context.i64_type();
+    let f64_type = compiler.context.f64_type();
+
+    let buf = if buf_val.is_pointer_value() {
+        buf_val.into_pointer_value()
+    } else {
+        return Err(CompileError::TypeError(format!("{}: buf must be a pointer", name), span));
+    };
+    let cap = to_i64(compiler, cap_val, false)?;
+
+    // C varargs promote floats to double
+    let value = match value_val {
+        BasicValueEnum::FloatValue(f) if f.get_type() == f64_type => f,
+        BasicValueEnum::FloatValue(f) => compiler.builder.build_float_ext(f, f64_type, "fpext")?,
+        BasicValueEnum::IntValue(i) => compiler.builder.build_signed_int_to_float(i, f64_type, "sitofp")?,
+        _ => return Err(CompileError::TypeError(format!("{}: value must be a float", name), span)),
+    };
+
+    // The `*` precision argument is a C int
+    let precision = if prec_val.is_int_value() {
+        to_int_width(compiler, prec_val.into_int_value(), i32_type, true)?
+    } else {
+        return Err(CompileError::TypeError(format!("{}: precision must be an integer", name), span));
+    };
+
+    let snprintf_fn = compiler.module.get_function("snprintf").unwrap_or_else(|| {
+        let fn_type = i32_type.fn_type(&[ptr_type(compiler).into(), i64_type.into(), ptr_type(compiler).into()], true);
+        compiler.module.add_function("snprintf", fn_type, None)
+    });
+    let fmt = compiler.builder.build_global_string_ptr(format, "float_fmt")?;
+
+    let call = compiler.builder.build_call(
+        snprintf_fn,
+        &[buf.into(), cap.into(), fmt.as_pointer_value().into(), precision.into(), value.into()],
+        "snprintf_call",
+    )?;
+    let written = extract_call_result(call, "snprintf", compiler)?.into_int_value();
+    let written = compiler.builder.build_int_s_extend(written, i64_type, "written")?;
+
+    // snprintf reports the untruncated length (or negative on error)
+    let max_len = compiler.builder.build_int_sub(cap, i64_type.const_int(1, false), "max_len")?;
+    let too_long = compiler.builder.build_int_compare(inkwell::IntPredicate::SGT, written, max_len, "too_long")?;
+    let clamped = compiler.builder.build_select(too_long, max_len, written, "clamped")?.into_int_value();
+    let negative = compiler.builder.build_int_compare(inkwell::IntPredicate::SLT, clamped, i64_type.const_zero(), "negative")?;
+    Ok(compiler.builder.build_select(negative, i64_type.const_zero(), clamped, "len")?)
+}
+
+/// Format an f64 in fixed notation with a runtime precision ("%.*f")
+pub fn compile_format_f64<'ctx>(
+    compiler: &mut LLVMCompiler<'ctx>,
+    args: &[ast::Expression],
+) -> Result<BasicValueEnum<'ctx>, CompileError> {
+    compile_format_float(compiler, args, "format_f64", "%.*f")
+}
+
+/// Format an f64 in scientific notation with a runtime precision ("%.*e")
+pub fn compile_format_f64_sci<'ctx>(
+    compiler: &mut LLVMCompiler<'ctx>,
+    args: &[ast::Expression],
+) -> Result<BasicValueEnum<'ctx>, CompileError> {
+    compile_format_float(compiler, args, "format_f64_sci", "%.*e")
+}
+
+/// Format an f64 in the shorter of fixed and scientific notation with a runtime
+/// number of significant digits, dropping trailing zeros ("%.*g")
+pub fn compile_format_f64_g<'ctx>(
+    compiler: &mut LLVMCompiler<'ctx>,
+    args: &[ast::Expression],
+) -> Result<BasicValueEnum<'ctx>, CompileError> {
+    compile_format_float(compiler, args, "format_f64_g", "%.*g")
+}
+
+// =============================================================================
+// Random State
+// =============================================================================
+
+/// Name of the module-level PRNG state global
+const RANDOM_STATE_GLOBAL: &str = "__zen_random_state";
+
+/// Default (non-zero) PRNG state used until the program calls random.seed
+const RANDOM_DEFAULT_STATE: u64 = 0x0139_408D_CBBF_7A44;
+
+/// Get a pointer to the process-wide PRNG state (a single u64 global)
+pub fn compile_random_state<'ctx>(
+    compiler: &mut LLVMCompiler<'ctx>,
+    args: &[ast::Expression],
+) -> Result<BasicValueEnum<'ctx>, CompileError> {
+    require_args(args, 0, "random_state", compiler.get_current_span())?;
+    let i64_type = compiler.context.i64_type();
+    let global = compiler.module.get_global(RANDOM_STATE_GLOBAL).unwrap_or_else(|| {
+        let global = compiler.module.add_global(i64_type, None, RANDOM_STATE_GLOBAL);
+        global.set_initializer(&i64_type.const_int(RANDOM_DEFAULT_STATE, false));
+        global.set_linkage(Linkage::Internal);
+        global
+    });
+    Ok(global.as_pointer_value().into())
+}
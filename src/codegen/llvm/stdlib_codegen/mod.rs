@@ -8,7 +8,11 @@ pub mod helpers;
 // Re-export compiler intrinsics
 pub use compiler::{
     // Panic
+    build_panic,
     compile_panic,
+    // Core assert and layout queries
+    compile_core_assert,
+    compile_core_offset_of,
     // Inline C
     compile_inline_c,
     // Memory allocation
@@ -42,8 +46,11 @@ pub use compiler::{
     // Pointer conversion
     compile_int_to_ptr,
     compile_ptr_to_int,
-    // Sizeof
+    // Type layout
     compile_sizeof,
+    // Behavior dispatch
+    compile_eq_impl_or,
+    compile_hash_impl_or,
     // Memory operations
     compile_memcmp,
     compile_memcpy,
@@ -56,6 +63,7 @@ pub use compiler::{
     compile_ctlz,
     compile_ctpop,
     compile_cttz,
+    compile_is_little_endian,
     // Syscall intrinsics
     compile_syscall0,
     compile_syscall1,
@@ -67,4 +75,12 @@ pub use compiler::{
     // IO intrinsics (libc wrappers)
     compile_libc_write,
     compile_libc_read,
+    compile_stdout_write,
+    compile_stdout_flush,
+    // Float formatting
+    compile_format_f64,
+    compile_format_f64_sci,
+    compile_format_f64_g,
+    // Random state
+    compile_random_state,
 };
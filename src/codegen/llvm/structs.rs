@@ -70,7 +70,8 @@ impl<'ctx> LLVMCompiler<'ctx> {
         let field_llvm_type = self.to_llvm_type(&field_info.ast_type)?;
         let basic_type = self.to_basic_type(&field_llvm_type)?;
 
-        Ok(self.builder.build_load(basic_type, field_ptr, &format!("load_{}", field_name))?)
+        let unit = self.builder.build_load(basic_type, field_ptr, &format!("load_{}", field_name))?;
+        self.extract_bitfield(struct_name, field_name, unit)
     }
 
     /// Get struct name from AstType
@@ -81,6 +82,67 @@ impl<'ctx> LLVMCompiler<'ctx> {
             _ => None,
         }
     }
+
+    /// Bitfield layout (offset, width, field type) if the field is a bitfield
+    fn bitfield_info(&self, struct_name: &str, field_name: &str) -> Option<(u32, u32, AstType)> {
+        let info = self.struct_types.get(struct_name)?;
+        let &(offset, width) = info.bitfields.get(field_name)?;
+        let (_, field_type) = info.fields.get(field_name)?;
+        Some((offset, width, field_type.clone()))
+    }
+
+    /// Extract a bitfield from its loaded storage unit (ordinary fields pass through)
+    fn extract_bitfield(
+        &mut self,
+        struct_name: &str,
+        field_name: &str,
+        unit: BasicValueEnum<'ctx>,
+    ) -> Result<BasicValueEnum<'ctx>, CompileError> {
+        let Some((offset, width, field_type)) = self.bitfield_info(struct_name, field_name) else {
+            return Ok(unit);
+        };
+        let unit = unit.into_int_value();
+        let ty = unit.get_type();
+        let bits = ty.get_bit_width();
+
+        // Shift the field to the top, then back down (sign-extending signed fields)
+        let left = self.builder.build_left_shift(
+            unit, ty.const_int((bits - offset - width) as u64, false), "bf_left",
+        )?;
+        let value = self.builder.build_right_shift(
+            left, ty.const_int((bits - width) as u64, false), field_type.is_signed_integer(),
+            &format!("bf_{}", field_name),
+        )?;
+        Ok(value.into())
+    }
+
+    /// Store into a bitfield, preserving the other bits of its storage unit.
+    /// Returns false if the field is not a bitfield.
+    pub fn store_bitfield(
+        &mut self,
+        struct_name: &str,
+        field_name: &str,
+        field_ptr: inkwell::values::PointerValue<'ctx>,
+        value: BasicValueEnum<'ctx>,
+    ) -> Result<bool, CompileError> {
+        let Some((offset, width, field_type)) = self.bitfield_info(struct_name, field_name) else {
+            return Ok(false);
+        };
+        let field_llvm_type = self.to_llvm_type(&field_type)?;
+        let unit_type = self.to_basic_type(&field_llvm_type)?.into_int_type();
+        let value = self.builder.build_int_cast(value.into_int_value(), unit_type, "bf_cast")?;
+
+        let low_mask = if width >= 64 { u64::MAX } else { (1u64 << width) - 1 };
+        let field_mask = unit_type.const_int(low_mask << offset, false);
+
+        let old = self.builder.build_load(unit_type, field_ptr, "bf_unit")?.into_int_value();
+        let cleared = self.builder.build_and(old, field_mask.const_not(), "bf_cleared")?;
+        let bits = self.builder.build_and(value, unit_type.const_int(low_mask, false), "bf_bits")?;
+        let shifted = self.builder.build_left_shift(bits, unit_type.const_int(offset as u64, false), "bf_shifted")?;
+        let merged = self.builder.build_or(cleared, shifted, "bf_merged")?;
+        self.builder.build_store(field_ptr, merged)?;
+        Ok(true)
+    }
 }
 
 // ============================================================================
@@ -117,12 +179,21 @@ impl<'ctx> LLVMCompiler<'ctx> {
 
         let alloca = self.builder.build_alloca(llvm_type, &format!("{}_tmp", name))?;
 
+        // Bitfields share storage units, so unset neighbours must start zeroed
+        if self.struct_types.get(name).is_some_and(|info| !info.bitfields.is_empty()) {
+            self.builder.build_store(alloca, llvm_type.const_zero())?;
+        }
+
         for (field_name, field_index, field_type, field_expr) in fields_with_info {
             let field_val = self.compile_expression(&field_expr)?;
             let field_ptr = self.builder.build_struct_gep(
                 llvm_type, alloca, field_index as u32, &format!("{}_ptr", field_name),
             )?;
 
+            if self.store_bitfield(name, &field_name, field_ptr, field_val)? {
+                continue;
+            }
+
             let field_llvm_type = self.to_llvm_type(&field_type)?;
             match self.to_basic_type(&field_llvm_type) {
                 Ok(expected_type) => {
@@ -276,7 +347,8 @@ impl<'ctx> LLVMCompiler<'ctx> {
 
         let field_llvm_type = self.to_llvm_type(&field_info.ast_type)?;
         let basic_type = self.to_basic_type(&field_llvm_type)?;
-        Ok(self.builder.build_load(basic_type, field_ptr, &format!("load_{}_{}", var_name, field))?)
+        let unit = self.builder.build_load(basic_type, field_ptr, &format!("load_{}_{}", var_name, field))?;
+        self.extract_bitfield(struct_name, field, unit)
     }
 
     fn compile_deref_field_access(
@@ -363,7 +435,8 @@ impl<'ctx> LLVMCompiler<'ctx> {
 
         let field_llvm_type = self.to_llvm_type(&final_field_info.ast_type)?;
         let basic_type = self.to_basic_type(&field_llvm_type)?;
-        Ok(self.builder.build_load(basic_type, nested_ptr, &format!("load_{}", field))?)
+        let unit = self.builder.build_load(basic_type, nested_ptr, &format!("load_{}", field))?;
+        self.extract_bitfield(&nested_struct_name, field, unit)
     }
 
     fn compile_nested_from_value(
@@ -417,7 +490,8 @@ impl<'ctx> LLVMCompiler<'ctx> {
 
         let field_llvm_type = self.to_llvm_type(&field_info.ast_type)?;
         let basic_type = self.to_basic_type(&field_llvm_type)?;
-        Ok(self.builder.build_load(basic_type, field_ptr, &format!("load_{}", field))?)
+        let unit = self.builder.build_load(basic_type, field_ptr, &format!("load_{}", field))?;
+        self.extract_bitfield(&nested_name, field, unit)
     }
 
     fn compile_general_field_access(
@@ -447,7 +521,8 @@ impl<'ctx> LLVMCompiler<'ctx> {
 
         let field_llvm_type = self.to_llvm_type(&field_info.ast_type)?;
         let basic_type = self.to_basic_type(&field_llvm_type)?;
-        Ok(self.builder.build_load(basic_type, field_ptr, &format!("load_{}", field))?)
+        let unit = self.builder.build_load(basic_type, field_ptr, &format!("load_{}", field))?;
+        self.extract_bitfield(&struct_name, field, unit)
     }
 }
 
@@ -519,6 +594,40 @@ impl<'ctx> LLVMCompiler<'ctx> {
 // ============================================================================
 
 impl<'ctx> LLVMCompiler<'ctx> {
+    /// Store into `name.field` where `name` holds a struct or a pointer to one.
+    /// Struct values are written in place, so copies made earlier stay independent.
+    /// Returns false if `name` is neither, leaving the caller to handle it.
+    pub fn compile_identifier_field_assignment(
+        &mut self,
+        name: &str,
+        field: &str,
+        value: &Expression,
+    ) -> Result<bool, CompileError> {
+        let Some(var_info) = self.variables.get(name) else {
+            return Ok(false);
+        };
+        let (alloca, var_type) = (var_info.pointer, var_info.ast_type.clone());
+
+        let (struct_ptr, struct_name) = if let Some(struct_name) =
+            var_type.ptr_inner().and_then(|inner| self.struct_name_from_type(inner))
+        {
+            let ptr_type = self.context.ptr_type(inkwell::AddressSpace::default());
+            let struct_ptr = self.builder.build_load(ptr_type, alloca, &format!("load_{}_ptr", name))?;
+            (struct_ptr.into_pointer_value(), struct_name)
+        } else if let Some(struct_name) = self.struct_name_from_type(&var_type) {
+            (alloca, struct_name)
+        } else {
+            return Ok(false);
+        };
+
+        if !self.struct_types.contains_key(&struct_name) {
+            return Ok(false);
+        }
+        let val = self.compile_expression(value)?;
+        self.compile_struct_field_assignment(struct_ptr, field, val, &struct_name)?;
+        Ok(true)
+    }
+
     pub fn compile_struct_field_assignment(
         &mut self,
         struct_alloca: inkwell::values::PointerValue<'ctx>,
@@ -536,6 +645,10 @@ impl<'ctx> LLVMCompiler<'ctx> {
             struct_info.llvm_type, struct_alloca, field_info.index as u32, "field_ptr",
         )?;
 
+        if self.store_bitfield(struct_name, field_name, field_ptr, value)? {
+            return Ok(());
+        }
+
         let field_llvm_type = self.to_llvm_type(&field_info.ast_type)?;
         match self.to_basic_type(&field_llvm_type) {
             Ok(expected_type) => {
@@ -1,4 +1,4 @@
-use crate::ast::EnumVariant;
+use crate::ast::{AstType, EnumVariant};
 use inkwell::{
     types::{BasicTypeEnum, FunctionType, StructType},
     values::{FunctionValue, PointerValue},
@@ -14,6 +14,8 @@ pub struct EnumInfo<'ctx> {
     pub variant_indices: HashMap<String, u64>,
     /// The enum variants with their payloads
     pub variants: Vec<EnumVariant>,
+    /// Integer type used when crossing an FFI boundary (`repr(i32)`)
+    pub repr: Option<AstType>,
 }
 
 /// Represents a symbol in the symbol table, which can be a type, variable, or function
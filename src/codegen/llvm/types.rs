@@ -3,11 +3,42 @@ use crate::ast::{self, AstType};
 use crate::error::CompileError;
 use crate::stdlib_types::StdlibTypeRegistry;
 use inkwell::{
+    module::Module,
+    targets::{CodeModel, InitializationConfig, RelocMode, Target, TargetData, TargetMachine},
     types::{BasicMetadataTypeEnum, BasicType, BasicTypeEnum},
-    AddressSpace,
+    AddressSpace, OptimizationLevel,
 };
 use std::collections::HashMap;
 
+/// Give `module` the native target's triple and data layout, so layout decisions
+/// made during codegen match the object file later emitted for it. When the native
+/// target is unavailable the module keeps LLVM's default layout.
+pub(super) fn set_native_target(module: &Module<'_>) {
+    if Target::initialize_native(&InitializationConfig::default()).is_err() {
+        return;
+    }
+    let triple = TargetMachine::get_default_triple();
+    let machine = Target::from_triple(&triple).ok().and_then(|target| {
+        target.create_target_machine(
+            &triple,
+            "generic",
+            "",
+            OptimizationLevel::None,
+            RelocMode::Default,
+            CodeModel::Default,
+        )
+    });
+    if let Some(machine) = machine {
+        module.set_triple(&triple);
+        module.set_data_layout(&machine.get_target_data().get_data_layout());
+    }
+}
+
+/// Size and alignment queries answered from `module`'s data layout
+pub(super) fn module_target_data(module: &Module<'_>) -> TargetData {
+    TargetData::create(&module.get_data_layout().as_str().to_string_lossy())
+}
+
 impl<'ctx> LLVMCompiler<'ctx> {
     /// Convert primitive AstType to LLVM BasicTypeEnum
     /// Returns None for non-primitive types
@@ -363,8 +394,11 @@ impl<'ctx> LLVMCompiler<'ctx> {
     ) -> Result<(), CompileError> {
         let mut field_types = Vec::new();
         let mut fields = HashMap::new();
+        let mut bitfields = HashMap::new();
+        // Open bitfield storage unit: (llvm index, unit bits, bits used)
+        let mut bit_unit: Option<(usize, u32, u32)> = None;
 
-        for (index, field) in struct_def.fields.iter().enumerate() {
+        for field in struct_def.fields.iter() {
             // Use centralized primitive-to-LLVM conversion
             let llvm_type = if let Some(basic) = self.primitive_to_llvm_basic(&field.type_) {
                 basic
@@ -430,15 +464,62 @@ impl<'ctx> LLVMCompiler<'ctx> {
             }
             };
 
+            // Consecutive bitfields of the same type share a storage unit while they fit
+            if let Some(width) = field.bit_width.filter(|_| !struct_def.is_union) {
+                let unit_bits = crate::ast::bit_size(&field.type_).unwrap_or(64);
+                let (unit_index, offset) = match bit_unit {
+                    Some((i, bits, used)) if bits == unit_bits && used + width <= bits => (i, used),
+                    _ => {
+                        field_types.push(llvm_type);
+                        (field_types.len() - 1, 0)
+                    }
+                };
+                bit_unit = Some((unit_index, unit_bits, offset + width));
+                bitfields.insert(field.name.clone(), (offset, width));
+                fields.insert(field.name.clone(), (unit_index, field.type_.clone()));
+                continue;
+            }
+            bit_unit = None;
+
             field_types.push(llvm_type);
+            // Union fields all live at index 0 and are reinterpreted on load/store
+            let index = if struct_def.is_union { 0 } else { field_types.len() - 1 };
             fields.insert(field.name.clone(), (index, field.type_.clone()));
         }
 
-        let struct_type = self.context.struct_type(&field_types, false);
+        if struct_def.is_union {
+            // A union's storage is its most-aligned field, padded out to the largest field
+            let target_data = &self.target_data;
+            let size = field_types
+                .iter()
+                .map(|t| target_data.get_store_size(t))
+                .max()
+                .unwrap_or(0);
+            let aligned = field_types
+                .iter()
+                .copied()
+                .max_by_key(|t| target_data.get_abi_alignment(t));
+            field_types = match aligned {
+                Some(aligned) => {
+                    let align = target_data.get_abi_alignment(&aligned) as u64;
+                    let padding = size.div_ceil(align) * align - target_data.get_store_size(&aligned);
+                    let mut storage = vec![aligned];
+                    if padding > 0 {
+                        storage.push(self.context.i8_type().array_type(padding as u32).into());
+                    }
+                    storage
+                }
+                None => Vec::new(),
+            };
+        }
+
+        let struct_type = self.context.struct_type(&field_types, struct_def.is_packed);
 
         let struct_info = StructTypeInfo {
             llvm_type: struct_type,
             fields,
+            bitfields,
+            is_union: struct_def.is_union,
         };
 
         self.struct_types
@@ -505,6 +586,7 @@ impl<'ctx> LLVMCompiler<'ctx> {
             llvm_type: enum_struct_type,
             variant_indices,
             variants: enum_def.variants.clone(),
+            repr: enum_def.repr.clone(),
         };
 
         self.symbols
@@ -512,4 +594,25 @@ impl<'ctx> LLVMCompiler<'ctx> {
 
         Ok(())
     }
+
+    /// Integer representation of an enum declared with `repr(T)`, if any.
+    /// Such enums cross FFI boundaries as a bare discriminant of type T.
+    /// Only extern parameters and return values are lowered; a repr enum stored
+    /// in a struct field keeps the `{ discriminant }` layout.
+    pub fn enum_repr(&self, ty: &AstType) -> Option<AstType> {
+        self.repr_enum_info(ty).and_then(|enum_info| enum_info.repr.clone())
+    }
+
+    /// Enum info for a type declared with `repr(T)`
+    pub fn repr_enum_info(&self, ty: &AstType) -> Option<&symbols::EnumInfo<'ctx>> {
+        let name = match ty {
+            AstType::Enum { name, .. } | AstType::EnumType { name } => name,
+            AstType::Generic { name, type_args } if type_args.is_empty() => name,
+            _ => return None,
+        };
+        match self.symbols.lookup(name) {
+            Some(symbols::Symbol::EnumType(enum_info)) if enum_info.repr.is_some() => Some(enum_info),
+            _ => None,
+        }
+    }
 }
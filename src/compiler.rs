@@ -1,37 +1,84 @@
 //! The high-level compiler orchestrator.
 //! This module ties the frontend (parser) and the backend (codegen) together.
 
-use crate::ast::{Declaration, Program};
+use crate::ast::{Declaration, Expression, Function, Program, Statement};
 use crate::codegen::llvm::LLVMCompiler;
 use crate::comptime::ComptimeInterpreter;
 use crate::error::{CompileError, Result};
 use crate::module_system::{resolver::ModuleResolver, ModuleSystem};
+use crate::type_context::TypeContext;
 use crate::type_system::Monomorphizer;
 use crate::typechecker::TypeChecker;
+use inkwell::basic_block::BasicBlock;
 use inkwell::context::Context;
 use inkwell::module::Module;
+use inkwell::values::InstructionValue;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashSet};
+use std::time::{Duration, Instant};
 
 /// The main compiler structure.
 #[allow(dead_code)]
 pub struct Compiler<'ctx> {
     context: &'ctx Context,
+    debug_checks: bool,
+    coverage: bool,
+    /// Wall-clock duration of each pass of the most recent pipeline run, in order
+    pass_timings: RefCell<Vec<(&'static str, Duration)>>,
 }
 
 impl<'ctx> Compiler<'ctx> {
     #[allow(dead_code)]
     pub fn new(context: &'ctx Context) -> Self {
-        Self { context }
+        Self {
+            context,
+            debug_checks: true,
+            coverage: false,
+            pass_timings: RefCell::new(Vec::new()),
+        }
     }
 
-    /// Core compilation pipeline - shared by compile_llvm and get_module
-    #[allow(dead_code)]
-    fn run_pipeline(&self, program: &Program) -> Result<LLVMCompiler<'ctx>> {
+    /// Enable or disable runtime safety checks (e.g. null dereference guards).
+    /// Debug builds keep them on; release builds turn them off.
+    pub fn with_debug_checks(mut self, enabled: bool) -> Self {
+        self.debug_checks = enabled;
+        self
+    }
+
+    /// Count how often each function runs and print the counts to stderr when
+    /// `main` returns (`--instrument-coverage`).
+    pub fn with_coverage(mut self, enabled: bool) -> Self {
+        self.coverage = enabled;
+        self
+    }
+
+    /// Durations of the passes run by the last compilation (imports through verification).
+    pub fn pass_timings(&self) -> Vec<(&'static str, Duration)> {
+        self.pass_timings.borrow().clone()
+    }
+
+    /// Run one pipeline pass and record how long it took
+    fn time_pass<T>(&self, name: &'static str, pass: impl FnOnce() -> Result<T>) -> Result<T> {
+        let start = Instant::now();
+        let result = pass();
+        self.pass_timings.borrow_mut().push((name, start.elapsed()));
+        result
+    }
+
+    /// Frontend of the pipeline: imports, comptime, Self resolution and type checking.
+    /// Returns the processed program together with the TypeContext it produced.
+    fn run_frontend(&self, program: &Program) -> Result<(Program, TypeContext)> {
+        self.pass_timings.borrow_mut().clear();
+
         // Process module imports (this loads stdlib modules)
         let mut module_system = ModuleSystem::new();
-        let processed_program = self.process_imports_with_system(program, &mut module_system)?;
+        let processed_program = self.time_pass("imports", || {
+            self.process_imports_with_system(program, &mut module_system)
+        })?;
 
         // Execute comptime blocks and expressions
-        let processed_program = self.execute_comptime(processed_program)?;
+        let processed_program =
+            self.time_pass("comptime", || self.execute_comptime(processed_program))?;
 
         // Resolve Self types in trait implementations
         let processed_program = self.resolve_self_types(processed_program)?;
@@ -40,31 +87,46 @@ impl<'ctx> Compiler<'ctx> {
         // Pass loaded stdlib modules to TypeChecker so it can extract type info
         let mut typechecker = TypeChecker::new();
         typechecker.with_stdlib_modules(module_system.get_modules());
-        let type_ctx = typechecker.check_program(&processed_program)?;
+        let type_ctx =
+            self.time_pass("type checking", || typechecker.check_program(&processed_program))?;
+
+        Ok((processed_program, type_ctx))
+    }
+
+    /// Core compilation pipeline - shared by compile_llvm and get_module
+    #[allow(dead_code)]
+    fn run_pipeline(&self, program: &Program) -> Result<LLVMCompiler<'ctx>> {
+        let (processed_program, type_ctx) = self.run_frontend(program)?;
 
         // Monomorphize the program to resolve all generic types
         // Monomorphizer uses TypeContext for type lookups
         let mut monomorphizer = Monomorphizer::new(type_ctx);
-        let monomorphized_program = monomorphizer.monomorphize_program(&processed_program)?;
+        let monomorphized_program = self.time_pass("monomorphization", || {
+            monomorphizer.monomorphize_program(&processed_program)
+        })?;
 
         // Get TypeContext back from monomorphizer (possibly updated with new instantiations)
         let type_ctx = monomorphizer.into_type_context();
 
         // Pass TypeContext to codegen so it can look up types instead of re-inferring
         let mut llvm_compiler = LLVMCompiler::new(self.context, type_ctx);
-        llvm_compiler.compile_program(&monomorphized_program)?;
+        llvm_compiler.debug_checks = self.debug_checks;
+        llvm_compiler.coverage = self.coverage;
+        self.time_pass("codegen", || llvm_compiler.compile_program(&monomorphized_program))?;
 
         // Debug: Print LLVM IR before verification for debugging
         if std::env::var("DEBUG_LLVM").is_ok() {
             eprintln!("LLVM IR:\n{}", llvm_compiler.module.print_to_string());
         }
 
-        if let Err(e) = llvm_compiler.module.verify() {
-            return Err(CompileError::InternalError(
-                format!("LLVM verification error: {}", e.to_string()),
-                None,
-            ));
-        }
+        self.time_pass("verification", || {
+            llvm_compiler.module.verify().map_err(|e| {
+                CompileError::InternalError(
+                    format!("LLVM verification error: {}", e.to_string()),
+                    None,
+                )
+            })
+        })?;
 
         Ok(llvm_compiler)
     }
@@ -84,6 +146,83 @@ impl<'ctx> Compiler<'ctx> {
         Ok(llvm_compiler.module)
     }
 
+    /// Renders the control-flow graph of `function_name` in DOT format: one node per
+    /// basic block and one edge per branch target of its terminator.
+    pub fn dump_cfg(&self, program: &Program, function_name: &str) -> Result<String> {
+        let llvm_compiler = self.run_pipeline(program)?;
+        let function = llvm_compiler.module.get_function(function_name).ok_or_else(|| {
+            CompileError::UndeclaredFunction(function_name.to_string(), None)
+        })?;
+
+        // Unnamed blocks get a stable id from their position in the function
+        let blocks = function.get_basic_blocks();
+        let block_id = |block: BasicBlock<'ctx>| -> String {
+            let index = blocks.iter().position(|b| *b == block).unwrap_or(blocks.len());
+            match block.get_name().to_str() {
+                Ok(name) if !name.is_empty() => name.to_string(),
+                _ => format!("bb{}", index),
+            }
+        };
+
+        let mut out = format!("digraph \"{}\" {{\n", function_name);
+        for &block in &blocks {
+            let name = block_id(block);
+            out.push_str(&format!("    \"{}\";\n", name));
+            if let Some(terminator) = block.get_terminator() {
+                for target in branch_targets(terminator) {
+                    out.push_str(&format!("    \"{}\" -> \"{}\";\n", name, block_id(target)));
+                }
+            }
+        }
+        out.push_str("}\n");
+        Ok(out)
+    }
+
+    /// Type checks a program and renders its declarations with inferred types.
+    /// Only declarations written in `program` are shown, not merged stdlib code.
+    pub fn dump_typed_ast(&self, program: &Program) -> Result<String> {
+        let (_, type_ctx) = self.run_frontend(program)?;
+
+        let mut out = String::new();
+        for decl in &program.declarations {
+            match decl {
+                Declaration::Function(func) => dump_function(&mut out, func, &type_ctx),
+                Declaration::Struct(struct_def) => {
+                    let fields: Vec<String> = struct_def
+                        .fields
+                        .iter()
+                        .map(|f| format!("{}: {}", f.name, f.type_))
+                        .collect();
+                    out.push_str(&format!("{}: {{ {} }}\n", struct_def.name, fields.join(", ")));
+                }
+                Declaration::Enum(enum_def) => {
+                    let variants: Vec<String> = enum_def
+                        .variants
+                        .iter()
+                        .map(|v| match &v.payload {
+                            Some(payload) => format!("{}: {}", v.name, payload),
+                            None => v.name.clone(),
+                        })
+                        .collect();
+                    out.push_str(&format!("{}: {}\n", enum_def.name, variants.join(", ")));
+                }
+                Declaration::Constant { name, value, .. } => {
+                    out.push_str(&format!("{} = {:?}\n", name, value));
+                }
+                _ => {}
+            }
+        }
+        Ok(out)
+    }
+
+    /// Source text of every module `program` imports, directly or transitively,
+    /// keyed by module path
+    pub fn imported_sources(&self, program: &Program) -> Result<BTreeMap<String, String>> {
+        let mut module_system = ModuleSystem::new();
+        self.process_imports_with_system(program, &mut module_system)?;
+        Ok(module_system.loaded_sources().clone())
+    }
+
     /// Process module imports and merge imported modules
     #[allow(dead_code)]
     fn process_imports(&self, program: &Program) -> Result<Program> {
@@ -104,11 +243,9 @@ impl<'ctx> Compiler<'ctx> {
                 // Register the import with the resolver
                 resolver.add_import(alias.clone(), module_path.clone());
 
-                // Extract and register exports
-                if let Some(module) = module_system.get_modules().get(module_path) {
-                    let exports = ModuleResolver::extract_exports(module);
-                    resolver.add_exports(module_path.clone(), exports);
-                }
+                // Extract and register exports, including re-exported symbols
+                let exports = module_system.exported_symbols(module_path);
+                resolver.add_exports(module_path.clone(), exports);
             }
         }
 
@@ -129,6 +266,15 @@ impl<'ctx> Compiler<'ctx> {
         let mut interpreter = ComptimeInterpreter::new();
         let mut new_declarations = Vec::new();
 
+        // Expose declared types to comptime introspection
+        for decl in &program.declarations {
+            match decl {
+                Declaration::Struct(struct_def) => interpreter.register_struct(struct_def),
+                Declaration::Enum(enum_def) => interpreter.register_enum(enum_def),
+                _ => {}
+            }
+        }
+
         for decl in program.declarations {
             match decl {
                 Declaration::ComptimeBlock(statements) => {
@@ -136,7 +282,7 @@ impl<'ctx> Compiler<'ctx> {
                     interpreter.execute_comptime_block(&statements)?;
 
                     // Get any generated declarations from the comptime execution
-                    let mut generated = interpreter.get_generated_declarations();
+                    let mut generated = interpreter.take_generated_declarations();
                     new_declarations.append(&mut generated);
                 }
                 other => {
@@ -252,6 +398,10 @@ impl<'ctx> Compiler<'ctx> {
                 body: self.process_statements_comptime(body, interpreter)?,
                 span,
             }),
+            Statement::Block { statements, span } => Ok(Statement::Block {
+                statements: self.process_statements_comptime(statements, interpreter)?,
+                span,
+            }),
             Statement::ComptimeBlock { statements, .. } => {
                 // Execute the comptime block inline
                 interpreter.execute_comptime_block(&statements)?;
@@ -283,11 +433,71 @@ impl<'ctx> Compiler<'ctx> {
                 // Convert the computed value back to an expression
                 value.to_expression()
             }
+            // `comptime.<builtin>(...)` calls fold into literals
+            call @ Expression::MethodCall { .. } if is_comptime_builtin_call(&call) => {
+                interpreter.evaluate_expression(&call)?.to_expression()
+            }
             Expression::BinaryOp { left, op, right } => Ok(Expression::BinaryOp {
                 left: Box::new(self.process_expression_comptime(*left, interpreter)?),
                 op,
                 right: Box::new(self.process_expression_comptime(*right, interpreter)?),
             }),
+            Expression::UnaryOp { op, operand } => Ok(Expression::UnaryOp {
+                op,
+                operand: Box::new(self.process_expression_comptime(*operand, interpreter)?),
+            }),
+            Expression::MethodCall { object, method, type_args, args } => {
+                let mut processed_args = Vec::new();
+                for arg in args {
+                    processed_args.push(self.process_expression_comptime(arg, interpreter)?);
+                }
+                Ok(Expression::MethodCall {
+                    object: Box::new(self.process_expression_comptime(*object, interpreter)?),
+                    method,
+                    type_args,
+                    args: processed_args,
+                })
+            }
+            Expression::QuestionMatch { scrutinee, arms } => {
+                let mut processed_arms = Vec::new();
+                for arm in arms {
+                    processed_arms.push(crate::ast::MatchArm {
+                        pattern: arm.pattern,
+                        guard: arm
+                            .guard
+                            .map(|guard| self.process_expression_comptime(guard, interpreter))
+                            .transpose()?,
+                        body: self.process_expression_comptime(arm.body, interpreter)?,
+                    });
+                }
+                Ok(Expression::QuestionMatch {
+                    scrutinee: Box::new(self.process_expression_comptime(*scrutinee, interpreter)?),
+                    arms: processed_arms,
+                })
+            }
+            Expression::Conditional { scrutinee, arms } => {
+                let mut processed_arms = Vec::new();
+                for arm in arms {
+                    processed_arms.push(crate::ast::ConditionalArm {
+                        pattern: arm.pattern,
+                        guard: arm
+                            .guard
+                            .map(|guard| self.process_expression_comptime(guard, interpreter))
+                            .transpose()?,
+                        body: self.process_expression_comptime(arm.body, interpreter)?,
+                    });
+                }
+                Ok(Expression::Conditional {
+                    scrutinee: Box::new(self.process_expression_comptime(*scrutinee, interpreter)?),
+                    arms: processed_arms,
+                })
+            }
+            Expression::Block(statements) => Ok(Expression::Block(
+                self.process_statements_comptime(statements, interpreter)?,
+            )),
+            Expression::Loop { body } => Ok(Expression::Loop {
+                body: Box::new(self.process_expression_comptime(*body, interpreter)?),
+            }),
             Expression::FunctionCall { name, type_args, args } => {
                 let mut processed_args = Vec::new();
                 for arg in args {
@@ -438,3 +648,67 @@ impl<'ctx> Compiler<'ctx> {
         errors
     }
 }
+
+/// Render a function signature followed by the inferred type of each local
+fn dump_function(out: &mut String, func: &Function, type_ctx: &TypeContext) {
+    let params: Vec<String> = func
+        .args
+        .iter()
+        .map(|(name, ty)| format!("{}: {}", name, ty))
+        .collect();
+    let return_type = type_ctx
+        .get_function_return_type(&func.name)
+        .unwrap_or_else(|| func.return_type.clone());
+    out.push_str(&format!("{} = ({}) {} {{\n", func.name, params.join(", "), return_type));
+
+    let mut seen = HashSet::new();
+    dump_locals(out, &func.name, &func.body, type_ctx, &mut seen);
+    out.push_str("}\n");
+}
+
+/// Render `name: type` for each local declared in `statements`, descending into loops and blocks
+fn dump_locals(
+    out: &mut String,
+    function: &str,
+    statements: &[Statement],
+    type_ctx: &TypeContext,
+    seen: &mut HashSet<String>,
+) {
+    for stmt in statements {
+        match stmt {
+            Statement::VariableDeclaration { name, is_mutable, .. } => {
+                if seen.insert(name.clone()) {
+                    if let Some(ty) = type_ctx.get_variable_type(function, name) {
+                        let sep = if *is_mutable { "::" } else { ":" };
+                        out.push_str(&format!("    {}{} {}\n", name, sep, ty));
+                    }
+                }
+            }
+            Statement::VariableAssignment { name, .. } => {
+                if seen.insert(name.clone()) {
+                    if let Some(ty) = type_ctx.get_variable_type(function, name) {
+                        out.push_str(&format!("    {}: {}\n", name, ty));
+                    }
+                }
+            }
+            Statement::Loop { body, .. } => dump_locals(out, function, body, type_ctx, seen),
+            Statement::Block { statements, .. } => {
+                dump_locals(out, function, statements, type_ctx, seen)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Whether `expr` is a `comptime.<builtin>(...)` call
+fn is_comptime_builtin_call(expr: &Expression) -> bool {
+    matches!(expr, Expression::MethodCall { object, .. }
+        if matches!(object.as_ref(), Expression::Identifier(name) if name == "comptime"))
+}
+
+/// Blocks a terminator can jump to: its basic-block operands, in operand order
+fn branch_targets(terminator: InstructionValue<'_>) -> Vec<BasicBlock<'_>> {
+    (0..terminator.get_num_operands())
+        .filter_map(|i| terminator.get_operand(i)?.right())
+        .collect()
+}
@@ -3,10 +3,15 @@
 
 use crate::ast::{self, AstType, Declaration, Expression, Statement};
 use crate::error::{CompileError, Result};
+use crate::lexer::{Lexer, Token};
+use crate::parser::Parser;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 
+/// Stdlib collections with a `clone(allocator)` that copies their storage
+const DEEP_CLONED_COLLECTIONS: &[&str] = &["Vec", "String", "HashMap", "HashSet"];
+
 // Value types that can exist at compile time
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -184,6 +189,10 @@ pub struct ComptimeInterpreter {
     generated_declarations: Vec<Declaration>,
     // Track imports and modules
     modules: HashMap<String, ComptimeValue>,
+    // Fields of declared structs, in declaration order (for introspection)
+    struct_fields: HashMap<String, Vec<(String, AstType)>>,
+    // Variants of declared enums, in declaration order
+    enum_variants: HashMap<String, Vec<ast::EnumVariant>>,
 }
 
 impl Default for ComptimeInterpreter {
@@ -192,6 +201,8 @@ impl Default for ComptimeInterpreter {
             env: Environment::new(),
             generated_declarations: Vec::new(),
             modules: HashMap::new(),
+            struct_fields: HashMap::new(),
+            enum_variants: HashMap::new(),
         };
 
         // Initialize built-in compile-time functions
@@ -216,6 +227,293 @@ impl ComptimeInterpreter {
         self.env.variables.borrow().get(name).cloned()
     }
 
+    /// Make a struct's layout visible to comptime introspection builtins
+    pub fn register_struct(&mut self, struct_def: &ast::StructDefinition) {
+        let fields = struct_def
+            .fields
+            .iter()
+            .map(|f| (f.name.clone(), f.type_.clone()))
+            .collect();
+        self.struct_fields.insert(struct_def.name.clone(), fields);
+    }
+
+    /// Make an enum's variants visible to comptime introspection builtins
+    pub fn register_enum(&mut self, enum_def: &ast::EnumDefinition) {
+        self.enum_variants
+            .insert(enum_def.name.clone(), enum_def.variants.clone());
+    }
+
+    /// Enum type named by a builtin's single argument, with its variants
+    fn enum_arg(&self, builtin: &str, args: &[Expression]) -> Result<(String, Vec<ast::EnumVariant>)> {
+        let [Expression::Identifier(type_name)] = args else {
+            return Err(CompileError::ComptimeError(format!(
+                "comptime.{} expects an enum type name",
+                builtin
+            )));
+        };
+        let variants = self.enum_variants.get(type_name).cloned().ok_or_else(|| {
+            CompileError::ComptimeError(format!(
+                "comptime.{}: unknown enum type '{}'",
+                builtin, type_name
+            ))
+        })?;
+        Ok((type_name.clone(), variants))
+    }
+
+    /// Struct type named by a builtin's single argument, with its fields
+    fn struct_arg(&self, builtin: &str, args: &[Expression]) -> Result<(String, Vec<(String, AstType)>)> {
+        let [Expression::Identifier(type_name)] = args else {
+            return Err(CompileError::ComptimeError(format!(
+                "comptime.{} expects a struct type name",
+                builtin
+            )));
+        };
+        let fields = self.struct_fields.get(type_name).cloned().ok_or_else(|| {
+            CompileError::ComptimeError(format!(
+                "comptime.{}: unknown struct type '{}'",
+                builtin, type_name
+            ))
+        })?;
+        Ok((type_name.clone(), fields))
+    }
+
+    /// Build a field-by-field copy of `source` (spelled `path` in source form),
+    /// recursing into struct-typed fields. Without an allocator every other field,
+    /// pointers included, is copied as is. With one, collection fields get their own
+    /// storage via `clone(allocator)` and `Ptr`/`MutPtr` fields get a fresh copy of
+    /// their target, whose statements are appended to `prelude`. `RawPtr` fields
+    /// stay shared: the size of their target is not known.
+    fn clone_expression(
+        &self,
+        source: Expression,
+        path: &str,
+        type_name: &str,
+        allocator: Option<&Expression>,
+        prelude: &mut Vec<Statement>,
+    ) -> Result<Expression> {
+        let fields = self.struct_fields.get(type_name).cloned().unwrap_or_default();
+        let mut values = Vec::new();
+        for (field, field_type) in fields {
+            let access = Expression::MemberAccess {
+                object: Box::new(source.clone()),
+                member: field.clone(),
+            };
+            let field_path = format!("{}.{}", path, field);
+            let value = match (&field_type, allocator) {
+                (AstType::Generic { name, type_args }, _)
+                    if type_args.is_empty() && self.struct_fields.contains_key(name) =>
+                {
+                    self.clone_expression(access, &field_path, name, allocator, prelude)?
+                }
+                (AstType::Generic { name, .. }, Some(allocator))
+                    if DEEP_CLONED_COLLECTIONS.contains(&name.as_str()) =>
+                {
+                    Expression::MethodCall {
+                        object: Box::new(access),
+                        method: "clone".to_string(),
+                        type_args: vec![],
+                        args: vec![allocator.clone()],
+                    }
+                }
+                (pointer, Some(_)) if pointer.is_ptr_type() && !pointer.is_raw_ptr() => {
+                    let copy = format!("__clone_{}", field_path.replace('.', "_"));
+                    prelude.extend(Self::pointee_copy(&copy, &field_path, pointer)?);
+                    Expression::Identifier(copy)
+                }
+                _ => access,
+            };
+            values.push((field, value));
+        }
+        Ok(Expression::StructLiteral {
+            name: type_name.to_string(),
+            fields: values,
+        })
+    }
+
+    /// Statements binding `copy` to a copy of the value `path` points to, placed in
+    /// memory from `allocator`; a null pointer is copied as null
+    fn pointee_copy(copy: &str, path: &str, pointer: &AstType) -> Result<Vec<Statement>> {
+        let pointee = pointer.ptr_inner().cloned().unwrap_or(AstType::U8);
+        let source = format!(
+            "{copy} ::= {path}
+            @builtin.is_null({path} as RawPtr<u8>) ?
+                | false {{
+                    {copy}_raw = allocator.allocate(@builtin.sizeof<{pointee}>())
+                    @builtin.memcpy({copy}_raw, {path} as RawPtr<u8>, @builtin.sizeof<{pointee}>())
+                    {copy} = {copy}_raw as {pointer}
+                }}
+                | true {{ }}"
+        );
+        let mut parser = Parser::new(Lexer::new(&source));
+        let mut statements = Vec::new();
+        while parser.current_token != Token::Eof {
+            statements.push(parser.parse_statement()?);
+        }
+        Ok(statements)
+    }
+
+    /// The single builder behind `derive_clone` and `derive_deep_clone`:
+    /// `Type.clone = (self: Type[, allocator: Allocator]) Type { ... }`
+    fn clone_method(&self, type_name: &str, deep: bool) -> Result<ast::Function> {
+        let allocator = Expression::Identifier("allocator".to_string());
+        let allocator = deep.then_some(&allocator);
+        let mut prelude = Vec::new();
+        let copy = self.clone_expression(
+            Expression::Identifier("self".to_string()),
+            "self",
+            type_name,
+            allocator,
+            &mut prelude,
+        )?;
+        let args = match allocator {
+            Some(_) => vec![(
+                "allocator".to_string(),
+                AstType::Generic {
+                    name: "Allocator".to_string(),
+                    type_args: vec![],
+                },
+            )],
+            None => vec![],
+        };
+        let self_type = AstType::Generic {
+            name: type_name.to_string(),
+            type_args: vec![],
+        };
+        Ok(Self::derived_method(
+            type_name,
+            format!("{}.clone", type_name),
+            args,
+            self_type,
+            prelude,
+            copy,
+        ))
+    }
+
+    /// Build a derived method `name = (self: Type, args...) return_type { body; return result }`
+    fn derived_method(
+        type_name: &str,
+        name: String,
+        args: Vec<(String, AstType)>,
+        return_type: AstType,
+        mut body: Vec<Statement>,
+        result: Expression,
+    ) -> ast::Function {
+        let self_type = AstType::Generic {
+            name: type_name.to_string(),
+            type_args: vec![],
+        };
+        let mut all_args = vec![("self".to_string(), self_type)];
+        all_args.extend(args);
+        ast::Function {
+            name,
+            type_params: vec![],
+            args: all_args,
+            return_type,
+            body: {
+                body.push(Statement::Return {
+                    expr: result,
+                    span: None,
+                });
+                body
+            },
+            is_varargs: false,
+            is_public: false,
+        }
+    }
+
+    /// Combine the fields of `self` into one u64: integers are cast, struct fields
+    /// call their own (derived) `hash`
+    fn hash_expression(&self, builtin: &str, type_name: &str, fields: &[(String, AstType)]) -> Result<Expression> {
+        let mut combined: Option<Expression> = None;
+        for (field, field_type) in fields {
+            let access = Expression::MemberAccess {
+                object: Box::new(Expression::Identifier("self".to_string())),
+                member: field.clone(),
+            };
+            let field_hash = match field_type {
+                t if t.is_integer() => Expression::FunctionCall {
+                    name: "cast".to_string(),
+                    type_args: vec![],
+                    args: vec![access, Expression::Identifier("u64".to_string())],
+                },
+                AstType::Generic { name, type_args }
+                    if type_args.is_empty() && self.struct_fields.contains_key(name) =>
+                {
+                    Expression::MethodCall {
+                        object: Box::new(access),
+                        method: "hash".to_string(),
+                        type_args: vec![],
+                        args: vec![],
+                    }
+                }
+                other => return Err(Self::underivable(builtin, type_name, field, other)),
+            };
+            combined = Some(match combined {
+                None => field_hash,
+                // h * 31 + field_hash
+                Some(acc) => Expression::BinaryOp {
+                    left: Box::new(Expression::BinaryOp {
+                        left: Box::new(acc),
+                        op: ast::BinaryOperator::Multiply,
+                        right: Box::new(Expression::Integer64(31)),
+                    }),
+                    op: ast::BinaryOperator::Add,
+                    right: Box::new(field_hash),
+                },
+            });
+        }
+        Ok(combined.unwrap_or_else(|| Expression::FunctionCall {
+            name: "cast".to_string(),
+            type_args: vec![],
+            args: vec![Expression::Integer64(0), Expression::Identifier("u64".to_string())],
+        }))
+    }
+
+    /// `self.a == other.a && ...`; struct fields call their own (derived) `equals`
+    fn equals_expression(&self, builtin: &str, type_name: &str, fields: &[(String, AstType)]) -> Result<Expression> {
+        let mut combined: Option<Expression> = None;
+        for (field, field_type) in fields {
+            let access = |side: &str| Expression::MemberAccess {
+                object: Box::new(Expression::Identifier(side.to_string())),
+                member: field.clone(),
+            };
+            let field_eq = match field_type {
+                t if t.is_numeric() || t.is_ptr_type() || matches!(t, AstType::Bool) => Expression::BinaryOp {
+                    left: Box::new(access("self")),
+                    op: ast::BinaryOperator::Equals,
+                    right: Box::new(access("other")),
+                },
+                AstType::Generic { name, type_args }
+                    if type_args.is_empty() && self.struct_fields.contains_key(name) =>
+                {
+                    Expression::MethodCall {
+                        object: Box::new(access("self")),
+                        method: "equals".to_string(),
+                        type_args: vec![],
+                        args: vec![access("other")],
+                    }
+                }
+                other => return Err(Self::underivable(builtin, type_name, field, other)),
+            };
+            combined = Some(match combined {
+                None => field_eq,
+                Some(acc) => Expression::BinaryOp {
+                    left: Box::new(acc),
+                    op: ast::BinaryOperator::And,
+                    right: Box::new(field_eq),
+                },
+            });
+        }
+        Ok(combined.unwrap_or(Expression::Boolean(true)))
+    }
+
+    fn underivable(builtin: &str, type_name: &str, field: &str, field_type: &AstType) -> CompileError {
+        CompileError::ComptimeError(format!(
+            "comptime.{}: field '{}.{}' has type {:?}, which the derive does not support",
+            builtin, type_name, field, field_type
+        ))
+    }
+
     fn init_builtins(&mut self) {
         // @std namespace
         self.modules.insert(
@@ -431,6 +729,22 @@ impl ComptimeInterpreter {
                 self.evaluate_binary_op(left_val, op, right_val)
             }
 
+            Expression::UnaryOp { op, operand } => {
+                let value = self.evaluate_expression(operand)?;
+                match (op, value) {
+                    (ast::UnaryOperator::Negate, ComptimeValue::I32(v)) => Ok(ComptimeValue::I32(-v)),
+                    (ast::UnaryOperator::Negate, ComptimeValue::I64(v)) => Ok(ComptimeValue::I64(-v)),
+                    (ast::UnaryOperator::Negate, ComptimeValue::F64(v)) => Ok(ComptimeValue::F64(-v)),
+                    (ast::UnaryOperator::BitwiseNot, ComptimeValue::I32(v)) => Ok(ComptimeValue::I32(!v)),
+                    (ast::UnaryOperator::BitwiseNot, ComptimeValue::I64(v)) => Ok(ComptimeValue::I64(!v)),
+                    (ast::UnaryOperator::Not, ComptimeValue::Bool(v)) => Ok(ComptimeValue::Bool(!v)),
+                    (op, value) => Err(CompileError::ComptimeError(format!(
+                        "Cannot apply {:?} to {:?} at compile time",
+                        op, value
+                    ))),
+                }
+            }
+
             Expression::FunctionCall { name, args, .. } => self.evaluate_function_call(name, args),
 
             Expression::ArrayLiteral(elements) => {
@@ -446,11 +760,49 @@ impl ComptimeInterpreter {
                 self.evaluate_member_access(obj_val, member)
             }
 
+            Expression::MethodCall { object, method, args, .. }
+                if matches!(object.as_ref(), Expression::Identifier(id) if id == "comptime") =>
+            {
+                self.evaluate_comptime_builtin(method, args)
+            }
+
             Expression::Comptime(inner) => {
                 // Nested comptime expression
                 self.evaluate_expression(inner)
             }
 
+            // `items.loop((item) { ... })` runs the closure body once per array element
+            Expression::MethodCall { object, method, args, .. } if method == "loop" => {
+                let items = match self.evaluate_expression(object)? {
+                    ComptimeValue::Array(items) => items,
+                    other => {
+                        return Err(CompileError::ComptimeError(format!(
+                            "loop at compile time needs an array, got {:?}",
+                            other
+                        )))
+                    }
+                };
+                let [Expression::Closure { params, body, .. }] = args.as_slice() else {
+                    return Err(CompileError::ComptimeError(
+                        "loop at compile time expects a single closure argument".to_string(),
+                    ));
+                };
+                for item in items {
+                    let loop_env = Environment::with_parent(self.env.clone());
+                    if let Some((param, _)) = params.first() {
+                        loop_env.define(param.clone(), item);
+                    }
+                    let saved_env = std::mem::replace(&mut self.env, loop_env);
+                    let result = match body.as_ref() {
+                        Expression::Block(statements) => self.execute_comptime_block(statements),
+                        expr => self.evaluate_expression(expr).map(|_| ()),
+                    };
+                    self.env = saved_env;
+                    result?;
+                }
+                Ok(ComptimeValue::Void)
+            }
+
             Expression::Range {
                 start,
                 end,
@@ -528,6 +880,7 @@ impl ComptimeInterpreter {
             },
 
             (ComptimeValue::String(l), ComptimeValue::String(r)) => match op {
+                BinaryOperator::Add => Ok(ComptimeValue::String(l + &r)),
                 BinaryOperator::Equals => Ok(ComptimeValue::Bool(l == r)),
                 BinaryOperator::NotEquals => Ok(ComptimeValue::Bool(l != r)),
                 _ => Err(CompileError::ComptimeError(format!(
@@ -629,6 +982,148 @@ impl ComptimeInterpreter {
         }
     }
 
+    /// Evaluate `comptime.<name>(args)` builtins
+    fn evaluate_comptime_builtin(&mut self, name: &str, args: &[Expression]) -> Result<ComptimeValue> {
+        match name {
+            "assert" => {
+                if args.is_empty() || args.len() > 2 {
+                    return Err(CompileError::ComptimeError(
+                        "comptime.assert expects a condition and an optional message".to_string(),
+                    ));
+                }
+                let message = match args.get(1) {
+                    Some(arg) => match self.evaluate_expression(arg)? {
+                        ComptimeValue::String(s) => s,
+                        _ => {
+                            return Err(CompileError::ComptimeError(
+                                "comptime.assert message must be a string".to_string(),
+                            ))
+                        }
+                    },
+                    None => "condition was false".to_string(),
+                };
+                match self.evaluate_expression(&args[0])? {
+                    ComptimeValue::Bool(true) => Ok(ComptimeValue::Void),
+                    ComptimeValue::Bool(false) => Err(CompileError::ComptimeError(format!(
+                        "Compile-time assertion failed: {}",
+                        message
+                    ))),
+                    _ => Err(CompileError::ComptimeError(
+                        "comptime.assert expects a boolean condition".to_string(),
+                    )),
+                }
+            }
+            "field_count" => {
+                let (_, fields) = self.struct_arg(name, args)?;
+                Ok(ComptimeValue::I32(fields.len() as i32))
+            }
+            "field_names" => {
+                let (_, fields) = self.struct_arg(name, args)?;
+                Ok(ComptimeValue::Array(
+                    fields.into_iter().map(|(f, _)| ComptimeValue::String(f)).collect(),
+                ))
+            }
+            "derive_clone" => {
+                // Generates `Type.clone = (self: Type) Type { return Type { f: self.f, ... } }`;
+                // pointer and collection fields still share their target with the original
+                let (type_name, _) = self.struct_arg(name, args)?;
+                let clone = self.clone_method(&type_name, false)?;
+                self.generated_declarations.push(Declaration::Function(clone));
+                Ok(ComptimeValue::Void)
+            }
+            "derive_deep_clone" => {
+                // Generates `Type.clone = (self: Type, allocator: Allocator) Type`, giving
+                // collection and Ptr/MutPtr fields their own copy from the allocator
+                let (type_name, _) = self.struct_arg(name, args)?;
+                let clone = self.clone_method(&type_name, true)?;
+                self.generated_declarations.push(Declaration::Function(clone));
+                Ok(ComptimeValue::Void)
+            }
+            "derive_hashable" => {
+                // Generates `Type.implements(Hashable, { hash = (self: Type) u64 { ... } })`
+                let (type_name, fields) = self.struct_arg(name, args)?;
+                let body = self.hash_expression(name, &type_name, &fields)?;
+                let hash = Self::derived_method(&type_name, "hash".to_string(), vec![], AstType::U64, vec![], body);
+                self.generated_declarations.push(Declaration::TraitImplementation(ast::TraitImplementation {
+                    type_name,
+                    trait_name: "Hashable".to_string(),
+                    type_params: vec![],
+                    methods: vec![hash],
+                }));
+                Ok(ComptimeValue::Void)
+            }
+            "derive_comparable" => {
+                // Generates `Type.implements(Comparable, { equals = (self: Type, other: Type) bool { ... } })`
+                let (type_name, fields) = self.struct_arg(name, args)?;
+                let body = self.equals_expression(name, &type_name, &fields)?;
+                let other = (
+                    "other".to_string(),
+                    AstType::Generic {
+                        name: type_name.clone(),
+                        type_args: vec![],
+                    },
+                );
+                let equals =
+                    Self::derived_method(&type_name, "equals".to_string(), vec![other], AstType::Bool, vec![], body);
+                self.generated_declarations.push(Declaration::TraitImplementation(ast::TraitImplementation {
+                    type_name,
+                    trait_name: "Comparable".to_string(),
+                    type_params: vec![],
+                    methods: vec![equals],
+                }));
+                Ok(ComptimeValue::Void)
+            }
+            "variants" => {
+                let (_, variants) = self.enum_arg(name, args)?;
+                Ok(ComptimeValue::Array(
+                    variants.into_iter().map(|v| ComptimeValue::String(v.name)).collect(),
+                ))
+            }
+            "derive_name" => {
+                // Generates `Type.name = (self: Type) StaticString { return self ? | Type.A { "A" } ... }`
+                let (type_name, variants) = self.enum_arg(name, args)?;
+                let arms = variants
+                    .into_iter()
+                    .map(|v| ast::MatchArm {
+                        pattern: ast::Pattern::EnumVariant {
+                            enum_name: type_name.clone(),
+                            variant: v.name.clone(),
+                            payload: v.payload.map(|_| Box::new(ast::Pattern::Wildcard)),
+                        },
+                        guard: None,
+                        body: Expression::String(v.name),
+                    })
+                    .collect();
+                self.generated_declarations.push(Declaration::Function(ast::Function {
+                    name: format!("{}.name", type_name),
+                    type_params: vec![],
+                    args: vec![(
+                        "self".to_string(),
+                        AstType::Generic {
+                            name: type_name.clone(),
+                            type_args: vec![],
+                        },
+                    )],
+                    return_type: AstType::StaticString,
+                    body: vec![Statement::Return {
+                        expr: Expression::QuestionMatch {
+                            scrutinee: Box::new(Expression::Identifier("self".to_string())),
+                            arms,
+                        },
+                        span: None,
+                    }],
+                    is_varargs: false,
+                    is_public: false,
+                }));
+                Ok(ComptimeValue::Void)
+            }
+            _ => Err(CompileError::ComptimeError(format!(
+                "Unknown comptime builtin: comptime.{}",
+                name
+            ))),
+        }
+    }
+
     /// Evaluate member access
     fn evaluate_member_access(
         &mut self,
@@ -647,9 +1142,8 @@ impl ComptimeInterpreter {
     }
 
     /// Get any declarations generated during compile-time execution
-    #[allow(dead_code)]
-    pub fn get_generated_declarations(&self) -> Vec<Declaration> {
-        self.generated_declarations.clone()
+    pub fn take_generated_declarations(&mut self) -> Vec<Declaration> {
+        std::mem::take(&mut self.generated_declarations)
     }
 
     /// Generate code from compile-time values
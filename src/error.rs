@@ -381,6 +381,18 @@ impl CompileError {
         }
     }
 
+    /// Render the error rustc-style with a caret under its span (see [`render_diagnostic`])
+    pub fn render(&self, file: &str, source: &str, color: bool) -> String {
+        render_diagnostic(
+            Severity::Error,
+            &self.message(),
+            self.position(),
+            file,
+            source,
+            color,
+        )
+    }
+
     /// Get a detailed error message with suggestions for fixing
     #[allow(dead_code)]
     pub fn detailed_message(&self, source_lines: &[&str]) -> String {
@@ -614,3 +626,98 @@ impl CompileError {
 impl std::error::Error for CompileError {}
 
 pub type Result<T> = std::result::Result<T, CompileError>;
+
+/// How serious a rendered diagnostic is
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    pub fn label(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+
+    /// ANSI color used for the label and the caret
+    fn color_code(self) -> &'static str {
+        match self {
+            Severity::Error => "1;31",
+            Severity::Warning => "1;33",
+        }
+    }
+}
+
+/// Render a diagnostic rustc-style: a header, the offending source line and a
+/// caret (`^~~`) underlining the span. `color` adds ANSI escapes for terminals.
+pub fn render_diagnostic(
+    severity: Severity,
+    message: &str,
+    span: Option<&Span>,
+    file: &str,
+    source: &str,
+    color: bool,
+) -> String {
+    let paint = |code: &str, text: &str| {
+        if color {
+            format!("\x1b[{}m{}\x1b[0m", code, text)
+        } else {
+            text.to_string()
+        }
+    };
+
+    let mut out = format!(
+        "{}{}\n",
+        paint(severity.color_code(), severity.label()),
+        paint("1", &format!(": {}", message))
+    );
+
+    let Some(span) = span else {
+        out.push_str(&format!(" {} {}\n", paint("1;34", "-->"), file));
+        return out;
+    };
+
+    let gutter = " ".repeat(span.line.to_string().len());
+    let bar = paint("1;34", "|");
+    out.push_str(&format!(
+        "{}{} {}:{}:{}\n",
+        gutter,
+        paint("1;34", "-->"),
+        file,
+        span.line,
+        span.column + 1
+    ));
+
+    let Some(line) = span.line.checked_sub(1).and_then(|i| source.lines().nth(i)) else {
+        return out;
+    };
+
+    // Tabs are kept so the caret lines up with the source line as the terminal draws it
+    let indent: String = line
+        .chars()
+        .take(span.column)
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+    let remaining = line.chars().count().saturating_sub(span.column).max(1);
+    let width = span.end.saturating_sub(span.start).clamp(1, remaining);
+    let marker = format!("^{}", "~".repeat(width - 1));
+
+    out.push_str(&format!("{} {}\n", gutter, bar));
+    out.push_str(&format!(
+        "{} {} {}\n",
+        paint("1;34", &span.line.to_string()),
+        bar,
+        line
+    ));
+    out.push_str(&format!(
+        "{} {} {}{}\n",
+        gutter,
+        bar,
+        indent,
+        paint(severity.color_code(), &marker)
+    ));
+    out
+}
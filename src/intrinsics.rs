@@ -6,7 +6,7 @@
 //! Everything else (io, math, collections, etc.) should be written in Zen
 //! using these intrinsics.
 
-use crate::ast::AstType;
+use crate::ast::{AstType, Expression};
 use crate::error::CompileError;
 use std::collections::HashMap;
 use std::sync::OnceLock;
@@ -27,6 +27,59 @@ pub fn is_builtin_module(name: &str) -> bool {
     BUILTIN_MODULES.iter().any(|(n, _)| *n == name)
 }
 
+/// Modules under `@std` that are only in scope once imported, e.g. `{ math } = @std`
+pub const IMPORTABLE_STD_MODULES: &[&str] = &[
+    "build",
+    "collections",
+    "concurrency",
+    "ffi",
+    "hash",
+    "math",
+    "memory",
+    "random",
+    "sys",
+    "testing",
+    "time",
+];
+
+/// Check if a name is an `@std` module that needs an explicit import
+pub fn is_importable_std_module(name: &str) -> bool {
+    IMPORTABLE_STD_MODULES.contains(&name)
+}
+
+/// Import path of the math module, whose functions get float overloads
+pub const MATH_MODULE_PATH: &str = "@std.math";
+
+/// `@std.math` functions whose float arguments go to a separate C function,
+/// as (function, float version): `math.abs(-2.5)` calls `fabs`
+pub const MATH_FLOAT_OVERLOADS: &[(&str, &str)] = &[("abs", "fabs")];
+
+/// The function `math.<name>` calls when its argument is a float, if it differs
+pub fn math_float_overload(name: &str) -> Option<&'static str> {
+    MATH_FLOAT_OVERLOADS
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, float_name)| *float_name)
+}
+
+/// Split the arguments of `core.offset_of(Struct, field)` into the struct and field
+/// names. The field may be written bare or as a string literal.
+pub fn offset_of_operands(args: &[Expression]) -> Result<(&str, &str), String> {
+    let [struct_arg, field_arg] = args else {
+        return Err(format!(
+            "core.offset_of() expects a struct type and a field name, got {} argument(s)",
+            args.len()
+        ));
+    };
+    let Expression::Identifier(struct_name) = struct_arg else {
+        return Err("core.offset_of() expects a struct type as its first argument".to_string());
+    };
+    match field_arg {
+        Expression::Identifier(field) | Expression::String(field) => Ok((struct_name, field)),
+        _ => Err("core.offset_of() expects a field name as its second argument".to_string()),
+    }
+}
+
 /// Get module ID for codegen
 #[allow(dead_code)]
 pub fn module_id(name: &str) -> Option<u64> {
@@ -204,6 +257,7 @@ fn build_intrinsics() -> HashMap<String, Intrinsic> {
     intrinsic!(m, "ctlz" => ("value", AstType::U64) -> AstType::U64);
     intrinsic!(m, "cttz" => ("value", AstType::U64) -> AstType::U64);
     intrinsic!(m, "ctpop" => ("value", AstType::U64) -> AstType::U64);
+    intrinsic!(m, "is_little_endian" => () -> AstType::Bool);
 
     // Overflow-checked arithmetic
     intrinsic!(m, "add_overflow" => ("a", AstType::I64, "b", AstType::I64) -> overflow_result.clone());
@@ -284,12 +338,35 @@ fn build_intrinsics() -> HashMap<String, Intrinsic> {
     // IO operations (libc wrappers)
     intrinsic!(m, "libc_write" => ("fd", AstType::I32, "buf", ptr.clone(), "len", AstType::Usize) -> AstType::I64);
     intrinsic!(m, "libc_read" => ("fd", AstType::I32, "buf", ptr.clone(), "len", AstType::Usize) -> AstType::I64);
+    intrinsic!(m, "stdout_write" => ("buf", ptr.clone(), "len", AstType::Usize) -> AstType::I64);
+    intrinsic!(m, "stdout_flush" => () -> AstType::I32);
+
+    // Float formatting (snprintf into caller-provided buffer)
+    for name in ["format_f64", "format_f64_sci", "format_f64_g"] {
+        m.insert(name.to_string(), Intrinsic {
+            name: name.to_string(),
+            params: vec![
+                ("buf".to_string(), ptr.clone()),
+                ("cap".to_string(), AstType::Usize),
+                ("value".to_string(), AstType::F64),
+                ("precision".to_string(), AstType::I32),
+            ],
+            return_type: AstType::I64,
+        });
+    }
+
+    // Process-wide PRNG state (pointer to a u64 global)
+    intrinsic!(m, "random_state" => () -> ptr.clone());
 
     // Generic load/store (type determined by context)
     let generic_t = AstType::Generic { name: "T".to_string(), type_args: vec![] };
     intrinsic!(m, "load" => ("ptr", ptr.clone()) -> generic_t.clone());
     intrinsic!(m, "store" => ("ptr", ptr.clone(), "value", generic_t.clone()) -> AstType::Void);
 
+    // Behavior dispatch (Hashable.hash / Comparable.equals if implemented for T, else default)
+    intrinsic!(m, "hash_impl_or" => ("key", generic_t.clone(), "default", AstType::U64) -> AstType::U64);
+    intrinsic!(m, "eq_impl_or" => ("a", generic_t.clone(), "b", generic_t.clone(), "default", AstType::Bool) -> AstType::Bool);
+
     // Enum intrinsics
     intrinsic!(m, "discriminant" => ("enum_value", ptr.clone()) -> AstType::I32);
     intrinsic!(m, "set_discriminant" => ("enum_ptr", ptr.clone(), "discriminant", AstType::I32) -> AstType::Void);
@@ -373,6 +373,10 @@ impl<'a> Lexer<'a> {
                 self.read_char();
                 Token::Operator("^".to_string())
             }
+            Some('~') => {
+                self.read_char();
+                Token::Operator("~".to_string())
+            }
             Some('!') => {
                 self.read_char();
                 if self.current_char == Some('=') {
@@ -391,6 +395,9 @@ impl<'a> Lexer<'a> {
                 if self.current_char == Some('>') {
                     self.read_char();
                     Token::Operator("->".to_string())
+                } else if self.current_char == Some('-') && !self.operand_follows() {
+                    self.read_char();
+                    Token::Operator("--".to_string())
                 } else {
                     Token::Operator("-".to_string())
                 }
@@ -651,7 +658,7 @@ impl<'a> Lexer<'a> {
             let two_char_op = format!("{}{}", first_char, second_char);
             if matches!(
                 two_char_op.as_str(),
-                "==" | "!=" | "<=" | ">=" | "&&" | "||" | ":=" | "::" | ".." | "..="
+                "==" | "!=" | "<=" | ">=" | "&&" | "||" | ":=" | "::" | ".." | "..=" | "++"
             ) {
                 self.read_char();
                 return two_char_op;
@@ -662,6 +669,18 @@ impl<'a> Lexer<'a> {
         first_char.to_string()
     }
 
+    /// Whether an operand starts after the character under the cursor on the same
+    /// line. `x--` is a decrement only at the end of a statement; in `x--1` or
+    /// `a--b` the dashes are a subtraction followed by a negation.
+    fn operand_follows(&self) -> bool {
+        self.input[self.read_position..]
+            .chars()
+            .find(|c| *c != ' ' && *c != '\t')
+            .is_some_and(|c| {
+                c.is_alphanumeric() || matches!(c, '_' | '(' | '[' | '"' | '\'' | '.' | '@' | '-' | '~')
+            })
+    }
+
     fn peek_char(&self) -> Option<char> {
         if self.read_position >= self.input.len() {
             None
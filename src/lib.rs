@@ -6,6 +6,7 @@ pub mod error;
 pub mod formatting;
 pub mod intrinsics;
 pub mod lexer;
+pub mod lints;
 pub mod lsp;
 pub mod module_system;
 pub mod parser;
@@ -0,0 +1,303 @@
+//! Lints: non-fatal diagnostics reported alongside compilation
+//!
+//! Lints never stop a program from compiling on their own; the CLI prints them
+//! as warnings and `--deny-warnings` turns any of them into a build failure.
+
+use crate::ast::{Declaration, Expression, Program, Statement, StringPart};
+use crate::error::Span;
+use crate::lexer::{Lexer, Token};
+use std::collections::HashMap;
+
+/// A diagnostic that does not prevent compilation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Warning {
+    pub message: String,
+    pub span: Option<Span>,
+}
+
+/// Run every lint over `program`, parsed from `source`
+pub fn check_program(program: &Program, source: &str) -> Vec<Warning> {
+    let mut warnings = unused_variables(program, source);
+    warnings.extend(mut_ref_of_immutable(program));
+    warnings
+}
+
+/// Warn about locals that are bound but never read.
+///
+/// A local is unused when every occurrence of its name in the source is a place
+/// that binds it (`x := ...`, `x ::= ...`, `x = ...`). Any other occurrence counts
+/// as a read, so the lint can miss unused locals that share a name with something
+/// else but never reports a local that is actually read. Names starting with `_`
+/// are exempt.
+pub fn unused_variables(program: &Program, source: &str) -> Vec<Warning> {
+    let mut first_bindings: Vec<(String, Option<Span>)> = Vec::new();
+    let mut binding_counts: HashMap<String, usize> = HashMap::new();
+
+    for declaration in &program.declarations {
+        if let Declaration::Function(func) = declaration {
+            let mut declared = Vec::new();
+            collect_bindings(&func.body, &mut declared, &mut binding_counts);
+            first_bindings.extend(declared);
+        }
+    }
+
+    let occurrences = identifier_occurrences(source);
+
+    first_bindings
+        .into_iter()
+        .filter(|(name, _)| !name.starts_with('_'))
+        .filter(|(name, _)| occurrences.get(name).copied().unwrap_or(0) <= binding_counts[name])
+        .map(|(name, span)| Warning {
+            message: format!("unused variable: `{}`", name),
+            span,
+        })
+        .collect()
+}
+
+/// Record every statement that binds a local, and the first binding of each
+/// name in `statements` (its declaration) into `first`
+fn collect_bindings(
+    statements: &[Statement],
+    first: &mut Vec<(String, Option<Span>)>,
+    counts: &mut HashMap<String, usize>,
+) {
+    for statement in statements {
+        match statement {
+            Statement::VariableDeclaration { name, span, .. }
+            | Statement::VariableAssignment { name, span, .. } => {
+                *counts.entry(name.clone()).or_insert(0) += 1;
+                if !first.iter().any(|(n, _)| n == name) {
+                    first.push((name.clone(), span.clone()));
+                }
+            }
+            Statement::Loop { body, .. } => collect_bindings(body, first, counts),
+            Statement::ComptimeBlock { statements, .. } | Statement::Block { statements, .. } => {
+                collect_bindings(statements, first, counts)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Count how often each identifier appears in `source`, including inside
+/// `${...}` string interpolations
+fn identifier_occurrences(source: &str) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    count_identifiers(source, &mut counts);
+    counts
+}
+
+fn count_identifiers(source: &str, counts: &mut HashMap<String, usize>) {
+    let mut lexer = Lexer::new(source);
+    loop {
+        match lexer.next_token() {
+            Token::Eof => break,
+            Token::Identifier(name) => *counts.entry(name).or_insert(0) += 1,
+            // The lexer marks interpolations with \x01 ... \x02 inside the literal
+            Token::StringLiteral(text) => {
+                for segment in text.split('\x01').skip(1) {
+                    let expr = segment.split('\x02').next().unwrap_or("");
+                    count_identifiers(expr, counts);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Warn about `.mut_ref()` on a local bound immutably (`x = ...`, `x: T = ...`).
+///
+/// This is the warning phase of a check that will become a type error; binding
+/// the local with `::=` (or `x:: T = ...`) silences it. Locals annotated with a
+/// pointer type, or initialized from `.ref()`/`.mut_ref()`, already refer to
+/// their target and are exempt. Function parameters are not checked.
+pub fn mut_ref_of_immutable(program: &Program) -> Vec<Warning> {
+    let mut warnings = Vec::new();
+    for declaration in &program.declarations {
+        if let Declaration::Function(func) = declaration {
+            let mut immutable = HashMap::new();
+            check_mut_refs(&func.body, &mut immutable, &mut warnings);
+        }
+    }
+    warnings
+}
+
+/// Walk `statements` in order, tracking which locals are immutable non-pointer
+/// bindings (`true`) and reporting mutable references taken to them
+fn check_mut_refs(
+    statements: &[Statement],
+    immutable: &mut HashMap<String, bool>,
+    warnings: &mut Vec<Warning>,
+) {
+    for statement in statements {
+        let span = statement_span(statement);
+        let mut report = |expr: &Expression, immutable: &HashMap<String, bool>| {
+            visit_expressions(expr, &mut |e| {
+                if let Expression::CreateMutableReference(inner) = e {
+                    if let Expression::Identifier(name) = inner.as_ref() {
+                        if immutable.get(name).copied().unwrap_or(false) {
+                            warnings.push(Warning {
+                                message: format!(
+                                    "mutable reference to immutable variable `{}`; declare it with `::=` (this will become an error)",
+                                    name
+                                ),
+                                span: span.clone(),
+                            });
+                        }
+                    }
+                }
+            });
+        };
+        match statement {
+            Statement::VariableDeclaration { name, type_, initializer, is_mutable, .. } => {
+                if let Some(init) = initializer {
+                    report(init, immutable);
+                }
+                // `x: T` followed by `x = ...` keeps the declaration's mutability
+                let refers = type_.as_ref().is_some_and(|t| t.is_ptr_type())
+                    || initializer.as_ref().is_some_and(is_reference);
+                immutable.insert(name.clone(), !*is_mutable && !refers);
+            }
+            Statement::VariableAssignment { name, value, .. } => {
+                report(value, immutable);
+                if !immutable.contains_key(name) {
+                    immutable.insert(name.clone(), !is_reference(value));
+                }
+            }
+            Statement::Expression { expr, .. }
+            | Statement::Return { expr, .. }
+            | Statement::ThisDefer { expr, .. } => report(expr, immutable),
+            Statement::PointerAssignment { pointer, value, .. } => {
+                report(pointer, immutable);
+                report(value, immutable);
+            }
+            Statement::Loop { body, .. } => check_mut_refs(body, immutable, warnings),
+            Statement::ComptimeBlock { statements, .. } | Statement::Block { statements, .. } => {
+                check_mut_refs(statements, immutable, warnings)
+            }
+            Statement::Defer { statement, .. } => {
+                check_mut_refs(std::slice::from_ref(statement.as_ref()), immutable, warnings)
+            }
+            _ => {}
+        }
+    }
+}
+
+fn is_reference(expr: &Expression) -> bool {
+    matches!(expr, Expression::CreateReference(_) | Expression::CreateMutableReference(_))
+}
+
+fn statement_span(statement: &Statement) -> Option<Span> {
+    match statement {
+        Statement::Expression { span, .. }
+        | Statement::Return { span, .. }
+        | Statement::VariableDeclaration { span, .. }
+        | Statement::VariableAssignment { span, .. }
+        | Statement::PointerAssignment { span, .. }
+        | Statement::ThisDefer { span, .. } => span.clone(),
+        _ => None,
+    }
+}
+
+/// Call `f` on `expr` and every expression nested in it, not descending into
+/// statements inside blocks and closures
+fn visit_expressions(expr: &Expression, f: &mut dyn FnMut(&Expression)) {
+    f(expr);
+    match expr {
+        Expression::BinaryOp { left, right, .. } => {
+            visit_expressions(left, f);
+            visit_expressions(right, f);
+        }
+        Expression::Range { start, end, .. } => {
+            visit_expressions(start, f);
+            visit_expressions(end, f);
+        }
+        Expression::ArrayIndex { array, index } => {
+            visit_expressions(array, f);
+            visit_expressions(index, f);
+        }
+        Expression::PointerOffset { pointer, offset } => {
+            visit_expressions(pointer, f);
+            visit_expressions(offset, f);
+        }
+        Expression::UnaryOp { operand: inner, .. }
+        | Expression::AddressOf(inner)
+        | Expression::Dereference(inner)
+        | Expression::PointerDereference(inner)
+        | Expression::PointerAddress(inner)
+        | Expression::CreateReference(inner)
+        | Expression::CreateMutableReference(inner)
+        | Expression::StringLength(inner)
+        | Expression::Some(inner)
+        | Expression::Comptime(inner)
+        | Expression::Return(inner)
+        | Expression::Raise(inner)
+        | Expression::Defer(inner)
+        | Expression::StructField { struct_: inner, .. }
+        | Expression::MemberAccess { object: inner, .. }
+        | Expression::TypeCast { expr: inner, .. } => visit_expressions(inner, f),
+        Expression::FunctionCall { args, .. } | Expression::ArrayLiteral(args) => {
+            args.iter().for_each(|arg| visit_expressions(arg, f));
+        }
+        Expression::MethodCall { object, args, .. } => {
+            visit_expressions(object, f);
+            args.iter().for_each(|arg| visit_expressions(arg, f));
+        }
+        Expression::StructLiteral { fields, .. } => {
+            fields.iter().for_each(|(_, value)| visit_expressions(value, f));
+        }
+        Expression::EnumVariant { payload: Some(payload), .. }
+        | Expression::EnumLiteral { payload: Some(payload), .. } => visit_expressions(payload, f),
+        Expression::StringInterpolation { parts } => {
+            for part in parts {
+                if let StringPart::Interpolation(inner) = part {
+                    visit_expressions(inner, f);
+                }
+            }
+        }
+        Expression::QuestionMatch { scrutinee, arms } => {
+            visit_expressions(scrutinee, f);
+            arms.iter().for_each(|arm| visit_expressions(&arm.body, f));
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mut_ref_warnings(source: &str) -> Vec<Warning> {
+        let lexer = Lexer::new(source);
+        let mut parser = crate::parser::Parser::new(lexer);
+        let program = parser.parse_program().expect("source should parse");
+        mut_ref_of_immutable(&program)
+    }
+
+    #[test]
+    fn test_mut_ref_of_immutable_binding_warns() {
+        let warnings = mut_ref_warnings(
+            "
+            main = () void {
+                x = 10
+                p = x.mut_ref()
+            }
+            ",
+        );
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("immutable variable `x`"));
+    }
+
+    #[test]
+    fn test_mut_ref_of_mutable_binding_is_clean() {
+        let warnings = mut_ref_warnings(
+            "
+            main = () void {
+                x ::= 10
+                p = x.mut_ref()
+            }
+            ",
+        );
+        assert!(warnings.is_empty());
+    }
+}
@@ -8,7 +8,7 @@ use super::types::SymbolInfo;
 use super::utils::{
     compile_error_to_diagnostic, compile_error_to_diagnostic_with_content, format_type,
 };
-use crate::ast::{Declaration, Expression, Program, Statement};
+use crate::ast::{AstType, Declaration, Expression, Program, Statement};
 use crate::stdlib_types::stdlib_types;
 use crate::lexer::Lexer;
 use crate::module_system::ModuleSystem;
@@ -239,6 +239,9 @@ fn check_allocator_in_expression(
             check_allocator_in_expression(left, diagnostics, content);
             check_allocator_in_expression(right, diagnostics, content);
         }
+        Expression::UnaryOp { operand, .. } => {
+            check_allocator_in_expression(operand, diagnostics, content);
+        }
         _ => {}
     }
 }
@@ -421,6 +424,20 @@ pub fn infer_type_from_expression_simple(expr: &Expression) -> Option<String> {
     }
 }
 
+/// Type built by a collection constructor such as `DynVec<i32>()` or `Vec<T, 8>()`
+pub fn collection_constructor_type(expr: &Expression) -> Option<AstType> {
+    let (name, type_args) = match expr {
+        Expression::VecConstructor { element_type, .. } => ("Vec", vec![element_type.clone()]),
+        Expression::DynVecConstructor { element_types, .. } => ("DynVec", element_types.clone()),
+        Expression::ArrayConstructor { element_type } => ("Array", vec![element_type.clone()]),
+        _ => return None,
+    };
+    Some(AstType::Generic {
+        name: name.to_string(),
+        type_args,
+    })
+}
+
 /// Infer type from expression with full document context
 pub fn infer_type_from_expression(
     expr: &Expression,
@@ -442,8 +459,17 @@ pub fn infer_type_from_expression(
         }
     }
 
+    if let Some(ast_type) = collection_constructor_type(expr) {
+        return Some(format_type(&ast_type));
+    }
+
     // Fallback
     match expr {
+        // `HashMap<i32, Point>.new(...)` parses as a call on an identifier that keeps its type arguments
+        Expression::MethodCall { object, method, .. } if method == "new" => match object.as_ref() {
+            Expression::Identifier(name) if name.contains('<') => Some(name.clone()),
+            _ => None,
+        },
         Expression::FunctionCall { name, .. } => {
             if let Some(sig) = compiler.get_function_signature(name) {
                 return Some(format_type(&sig.return_type));
@@ -467,11 +493,27 @@ pub fn infer_type_from_expression(
     }
 }
 
+/// Position of the scrutinee where it is matched on (`name ?`), falling back
+/// to the first occurrence of its name
 fn find_pattern_match_position(content: &str, scrutinee: &Expression) -> Option<Position> {
-    if let Expression::Identifier(name) = scrutinee {
-        return find_text_position(name, content);
+    let Expression::Identifier(name) = scrutinee else {
+        return None;
+    };
+    for (line_num, line) in content.lines().enumerate() {
+        for (col, _) in line.match_indices(name.as_str()) {
+            let starts_word = !matches!(
+                line[..col].chars().next_back(),
+                Some(c) if c.is_alphanumeric() || c == '_'
+            );
+            if starts_word && line[col + name.len()..].trim_start().starts_with('?') {
+                return Some(Position {
+                    line: line_num as u32,
+                    character: col as u32,
+                });
+            }
+        }
     }
-    None
+    find_text_position(name, content)
 }
 
 pub fn find_text_position(text: &str, content: &str) -> Option<Position> {
@@ -8,6 +8,8 @@ use std::sync::{Arc, Mutex};
 
 use super::document_store::DocumentStore;
 use super::helpers::{try_lock, try_parse_params, success_response};
+use crate::ast::Declaration;
+use crate::well_known::well_known;
 
 // ============================================================================
 // PUBLIC HANDLER FUNCTION
@@ -51,6 +53,18 @@ pub fn handle_code_action(req: Request, store: &Arc<Mutex<DocumentStore>>) -> Re
                 }
             }
 
+            if let Some(action) =
+                create_import_module_action(diagnostic, &params.text_document.uri)
+            {
+                actions.push(action);
+            }
+
+            if let Some(action) =
+                create_missing_arms_action(diagnostic, &params.text_document.uri, &doc.content, &store)
+            {
+                actions.push(action);
+            }
+
             if diagnostic.message.contains("Result") && diagnostic.message.contains("unwrap") {
                 if let Some(action) =
                     create_error_handling_action(diagnostic, &params.text_document.uri, &doc.content)
@@ -594,6 +608,196 @@ fn generate_variable_name(expression: &str) -> String {
     "extracted_value".to_string()
 }
 
+/// Offer `{ module } = @std` for a diagnostic about an `@std` module used without
+/// an import, e.g. "'math' is not imported; add it to an import list ..."
+fn create_import_module_action(diagnostic: &Diagnostic, uri: &Url) -> Option<CodeAction> {
+    let (_, rest) = diagnostic.message.split_once('\'')?;
+    let (module, rest) = rest.split_once('\'')?;
+    if !rest.starts_with(" is not imported") || !crate::intrinsics::is_importable_std_module(module) {
+        return None;
+    }
+
+    let import_statement = format!("{{ {} }} = @std\n", module);
+    let text_edit = TextEdit {
+        range: Range {
+            start: Position { line: 0, character: 0 },
+            end: Position { line: 0, character: 0 },
+        },
+        new_text: import_statement.clone(),
+    };
+
+    let mut changes = HashMap::new();
+    changes.insert(uri.clone(), vec![text_edit]);
+
+    Some(CodeAction {
+        title: format!("Add import: {}", import_statement.trim()),
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic.clone()]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: Some(true),
+        disabled: None,
+        data: None,
+    })
+}
+
+/// Add an empty arm for each variant named by a non-exhaustive match diagnostic,
+/// after the last arm of the first match at or below the diagnostic
+fn create_missing_arms_action(
+    diagnostic: &Diagnostic,
+    uri: &Url,
+    content: &str,
+    store: &DocumentStore,
+) -> Option<CodeAction> {
+    let (_, variant_list) = diagnostic.message.split_once("Missing variants: ")?;
+    let variants: Vec<&str> = variant_list.split(", ").map(str::trim).filter(|v| !v.is_empty()).collect();
+    if variants.is_empty() {
+        return None;
+    }
+
+    let lines: Vec<&str> = content.lines().collect();
+    let (question, first_arm, end) =
+        locate_match_arms(&lines, diagnostic.range.start.line as usize, diagnostic.range.start.character as usize)?;
+
+    // Follow the existing arms in writing `.Variant` or plain `Variant`
+    let first_arm_text = &lines[first_arm.0][first_arm.1 + 1..];
+    let prefix = if first_arm_text.trim_start().starts_with('.') { "." } else { "" };
+    // Single-line matches get inline arms; otherwise one arm per line at the arms' indent
+    let separator = if first_arm.0 == question.0 {
+        " ".to_string()
+    } else {
+        let line = lines[first_arm.0];
+        format!("\n{}", &line[..line.len() - line.trim_start().len()])
+    };
+    let new_text: String = variants
+        .iter()
+        .map(|variant| {
+            let payload = if variant_has_payload(variant, store) { "(_)" } else { "" };
+            format!("{}| {}{}{} {{ }}", separator, prefix, variant, payload)
+        })
+        .collect();
+
+    let insert_at = Position {
+        line: end.0 as u32,
+        character: end.1 as u32,
+    };
+    let mut changes = HashMap::new();
+    changes.insert(
+        uri.clone(),
+        vec![TextEdit {
+            range: Range {
+                start: insert_at,
+                end: insert_at,
+            },
+            new_text,
+        }],
+    );
+
+    Some(CodeAction {
+        title: format!("Add missing match arms: {}", variants.join(", ")),
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic.clone()]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: Some(true),
+        disabled: None,
+        data: None,
+    })
+}
+
+/// Find the `?` match whose scrutinee starts at (`line`, `col`). Returns the
+/// positions of the `?`, of the first arm's `|`, and just past the last arm's
+/// closing brace, or None when the text there is not a match with braced arms.
+fn locate_match_arms(
+    lines: &[&str],
+    line: usize,
+    col: usize,
+) -> Option<((usize, usize), (usize, usize), (usize, usize))> {
+    let chars: Vec<(usize, usize, char)> = lines
+        .iter()
+        .enumerate()
+        .skip(line)
+        .flat_map(|(i, text)| {
+            let skip = if i == line { col } else { 0 };
+            text.char_indices()
+                .filter(move |(c, _)| *c >= skip)
+                .map(move |(c, ch)| (i, c, ch))
+                .chain(std::iter::once((i, text.len(), '\n')))
+        })
+        .collect();
+    let next_non_ws = |from: usize| (from..chars.len()).find(|&k| !chars[k].2.is_whitespace());
+
+    let q = (0..chars.len()).find(|&k| chars[k].2 == '?' || chars[k].2 == '{')?;
+    if chars[q].2 != '?' {
+        return None;
+    }
+    let first = next_non_ws(q + 1)?;
+    if chars[first].2 != '|' {
+        return None;
+    }
+
+    let mut k = first;
+    let end = loop {
+        let open = (k..chars.len()).find(|&j| chars[j].2 == '{')?;
+        let mut depth = 0;
+        let mut in_string = false;
+        let mut close = None;
+        let mut j = open;
+        while j < chars.len() {
+            match chars[j].2 {
+                '\\' if in_string => j += 1,
+                '"' => in_string = !in_string,
+                '{' if !in_string => depth += 1,
+                '}' if !in_string => {
+                    depth -= 1;
+                    if depth == 0 {
+                        close = Some(j);
+                        break;
+                    }
+                }
+                _ => {}
+            }
+            j += 1;
+        }
+        let close = close?;
+        match next_non_ws(close + 1) {
+            Some(n) if chars[n].2 == '|' => k = n,
+            _ => break (chars[close].0, chars[close].1 + 1),
+        }
+    };
+
+    Some((
+        (chars[q].0, chars[q].1),
+        (chars[first].0, chars[first].1),
+        end,
+    ))
+}
+
+/// Whether `variant` carries a value, judging by Option/Result and the enums in open documents
+fn variant_has_payload(variant: &str, store: &DocumentStore) -> bool {
+    let wk = well_known();
+    if wk.is_some(variant) || wk.is_ok(variant) || wk.is_err(variant) {
+        return true;
+    }
+    store
+        .documents
+        .values()
+        .filter_map(|doc| doc.ast.as_ref())
+        .flatten()
+        .any(|decl| {
+            matches!(decl, Declaration::Enum(enum_def)
+                if enum_def.variants.iter().any(|v| v.name == variant && v.payload.is_some()))
+        })
+}
+
 fn create_add_import_action(uri: &Url, content: &str) -> Option<CodeAction> {
     let needs_io = content.contains("io.") && !content.contains("{ io }") && !content.contains("{io}");
     let needs_allocator = (content.contains("get_default_allocator")
@@ -642,3 +846,125 @@ fn create_add_import_action(uri: &Url, content: &str) -> Option<CodeAction> {
         data: None,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::handle_code_action;
+    use crate::lsp::document_store::DocumentStore;
+    use lsp_server::{Request, RequestId};
+    use lsp_types::*;
+    use std::sync::{Arc, Mutex};
+
+    /// Code actions offered at line 1 of a store holding only `content`
+    fn code_actions(content: &str, diagnostics: Vec<Diagnostic>) -> Vec<CodeAction> {
+        let uri = Url::parse("file:///tmp/actions.zen").unwrap();
+        let mut store = DocumentStore::new();
+        store.open(uri.clone(), 1, content.to_string());
+        let store = Arc::new(Mutex::new(store));
+
+        let position = Position { line: 1, character: 4 };
+        let params = CodeActionParams {
+            text_document: TextDocumentIdentifier { uri },
+            range: Range { start: position, end: position },
+            context: CodeActionContext { diagnostics, only: None, trigger_kind: None },
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+        let req = Request {
+            id: RequestId::from(1),
+            method: "textDocument/codeAction".to_string(),
+            params: serde_json::to_value(params).unwrap(),
+        };
+        let response = handle_code_action(req, &store);
+        serde_json::from_value(response.result.unwrap()).unwrap()
+    }
+
+    /// The text each action inserts at the top of the file
+    fn import_edits(actions: &[CodeAction]) -> Vec<String> {
+        actions
+            .iter()
+            .filter_map(|a| a.edit.as_ref()?.changes.as_ref())
+            .flat_map(|changes| changes.values().flatten())
+            .filter(|edit| edit.range.start == Position { line: 0, character: 0 })
+            .map(|edit| edit.new_text.clone())
+            .collect()
+    }
+
+    /// The diagnostics mentioning `needle` that the server publishes for `content`
+    fn published_diagnostics(content: &str, needle: &str) -> Vec<Diagnostic> {
+        let uri = Url::parse("file:///tmp/actions.zen").unwrap();
+        let mut store = DocumentStore::new();
+        store.open(uri, 1, content.to_string());
+        crate::lsp::analyzer::analyze_document(
+            content,
+            false,
+            &store.documents,
+            &store.workspace_symbols,
+            &store.stdlib_symbols,
+        )
+        .into_iter()
+        .filter(|d| d.message.contains(needle))
+        .collect()
+    }
+
+    /// The missing-arm diagnostics the server publishes for `content`
+    fn missing_arm_diagnostics(content: &str) -> Vec<Diagnostic> {
+        published_diagnostics(content, "Missing variants")
+    }
+
+    #[test]
+    fn test_add_import_for_unimported_module() {
+        let content = "{ io } = @std\n\nmain = () f64 {\n    return math.sqrt(4.0)\n}\n";
+        let diagnostics = published_diagnostics(content, "is not imported");
+        assert_eq!(diagnostics.len(), 1, "expected one published import diagnostic");
+
+        let actions = code_actions(content, diagnostics);
+        let action = actions
+            .iter()
+            .find(|a| a.title == "Add import: { math } = @std")
+            .expect("an import action for math");
+        assert_eq!(action.is_preferred, Some(true));
+        assert!(import_edits(&actions).contains(&"{ math } = @std\n".to_string()));
+    }
+
+    #[test]
+    fn test_builtin_module_publishes_no_import_diagnostic() {
+        // io is always in scope, so there is nothing for the import quick-fix to act on
+        let content = "main = () i32 {\n    io.println(\"hi\")\n    return 0\n}\n";
+        assert!(published_diagnostics(content, "is not imported").is_empty());
+    }
+
+    /// The edits of the quick-fix titled `title`
+    fn missing_arm_edits(content: &str, title: &str) -> Vec<TextEdit> {
+        let diagnostics = missing_arm_diagnostics(content);
+        assert_eq!(diagnostics.len(), 1, "expected one published missing-arm diagnostic");
+        let actions = code_actions(content, diagnostics);
+        let action = actions
+            .iter()
+            .find(|a| a.title == title)
+            .expect("a quick-fix for the missing arm");
+        action.edit.as_ref().unwrap().changes.as_ref().unwrap().values().flatten().cloned().collect()
+    }
+
+    #[test]
+    fn test_missing_match_arms_inserts_none_arm() {
+        let content = "main = () i32 {\n    value: Option<i32> = Option.Some(5)\n    value ?\n        | Some(x) {\n            return x\n        }\n    return 0\n}\n";
+        let diagnostics = missing_arm_diagnostics(content);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].range.start, Position { line: 2, character: 4 });
+
+        let edits = missing_arm_edits(content, "Add missing match arms: None");
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].range.start, Position { line: 5, character: 9 });
+        assert_eq!(edits[0].new_text, "\n        | None { }");
+    }
+
+    #[test]
+    fn test_missing_match_arms_single_line_match() {
+        let content = "main = () i32 {\n    v: Option<i32> = Option.Some(5)\n    v ? | Some(x) { return x }\n    return 0\n}\n";
+        let edits = missing_arm_edits(content, "Add missing match arms: None");
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].range.start, Position { line: 2, character: 30 });
+        assert_eq!(edits[0].new_text, " | None { }");
+    }
+}
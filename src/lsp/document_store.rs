@@ -888,6 +888,9 @@ impl DocumentStore {
                 self.find_references_in_expression(left, symbols);
                 self.find_references_in_expression(right, symbols);
             }
+            Expression::UnaryOp { operand, .. } => {
+                self.find_references_in_expression(operand, symbols);
+            }
             Expression::MemberAccess { object, .. } => {
                 self.find_references_in_expression(object, symbols);
             }
@@ -988,6 +991,8 @@ impl DocumentStore {
                     }
                 }
             }
+            // Collection constructors name their type, even before any document is parsed
+            return analyzer::collection_constructor_type(init);
         }
         None
     }
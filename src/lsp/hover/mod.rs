@@ -295,6 +295,14 @@ mod handler {
                         structs::format_struct_definition(&struct_def)
                     ));
                 }
+            } else if symbol_info.kind == SymbolKind::ENUM {
+                if let Some(enum_def) = structs::find_enum_definition(symbol_name, doc, store) {
+                    hover_content.clear();
+                    hover_content.push(format!(
+                        "```zen\n{}\n```",
+                        structs::format_enum_definition(&enum_def)
+                    ));
+                }
             }
 
             hover_content.push(format!(
@@ -648,3 +656,51 @@ mod handler {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::handle_hover;
+    use crate::lsp::document_store::DocumentStore;
+    use lsp_server::{Request, RequestId};
+    use lsp_types::Url;
+    use std::sync::{Arc, Mutex};
+
+    /// Hover text for `position` in a store holding only `content`
+    fn hover_text(content: &str, line: u32, character: u32) -> String {
+        let uri = Url::parse("file:///tmp/hover.zen").unwrap();
+        let mut store = DocumentStore::new();
+        store.open(uri.clone(), 1, content.to_string());
+        let store = Arc::new(Mutex::new(store));
+
+        let req = Request {
+            id: RequestId::from(1),
+            method: "textDocument/hover".to_string(),
+            params: serde_json::json!({
+                "textDocument": { "uri": uri },
+                "position": { "line": line, "character": character }
+            }),
+        };
+        handle_hover(req, &store).result.unwrap().to_string()
+    }
+
+    #[test]
+    fn test_hover_enum_lists_variants() {
+        let content = "Shape:\n    Circle: f64,\n    Empty\n\narea = (s: Shape) f64 {\n    return 0.0\n}\n";
+
+        // Hover over `Shape` in the parameter list of `area`
+        let result = hover_text(content, 4, 12);
+
+        assert!(result.contains("enum Shape {"), "hover was: {}", result);
+        assert!(result.contains("Circle(f64)"), "hover was: {}", result);
+        assert!(result.contains("Empty,"), "hover was: {}", result);
+    }
+
+    #[test]
+    fn test_hover_collection_variable_shows_element_type() {
+        let content = "main = () i32 {\n    numbers = DynVec<i32>()\n    return 0\n}\n";
+
+        let result = hover_text(content, 1, 5);
+
+        assert!(result.contains("DynVec<i32>"), "hover was: {}", result);
+    }
+}
@@ -21,6 +21,27 @@ pub fn format_struct_definition(struct_def: &crate::ast::StructDefinition) -> St
     result
 }
 
+/// Format enum definition with its variants for display
+pub fn format_enum_definition(enum_def: &crate::ast::EnumDefinition) -> String {
+    let mut result = String::from("enum ");
+    result.push_str(&enum_def.name);
+    if !enum_def.type_params.is_empty() {
+        let params: Vec<&str> = enum_def.type_params.iter().map(|p| p.name.as_str()).collect();
+        result.push_str(&format!("<{}>", params.join(", ")));
+    }
+    result.push_str(" {\n");
+    for variant in &enum_def.variants {
+        match &variant.payload {
+            Some(payload) => {
+                result.push_str(&format!("    {}({}),\n", variant.name, format_type(payload)))
+            }
+            None => result.push_str(&format!("    {},\n", variant.name)),
+        }
+    }
+    result.push('}');
+    result
+}
+
 /// Find struct definition by name in documents
 pub fn find_struct_definition(
     struct_name: &str,
@@ -54,6 +75,26 @@ pub fn find_struct_definition(
     None
 }
 
+/// Find enum definition by name in documents
+pub fn find_enum_definition(
+    enum_name: &str,
+    doc: &Document,
+    store: &DocumentStore,
+) -> Option<crate::ast::EnumDefinition> {
+    let current = doc.ast.iter();
+    let others = store.documents.values().filter_map(|d| d.ast.as_ref());
+    for ast in current.chain(others) {
+        for decl in ast {
+            if let Declaration::Enum(enum_def) = decl {
+                if enum_def.name == enum_name {
+                    return Some(enum_def.clone());
+                }
+            }
+        }
+    }
+    None
+}
+
 /// Find struct definition in documents map
 pub fn find_struct_definition_in_documents(
     struct_name: &str,
@@ -511,6 +511,8 @@ fn infer_expr_type(expr: &Expression, doc: &Document, store: &DocumentStore) ->
                  else if l == "i64" || r == "i64" { "i64" } 
                  else { "i32" }.to_string())
         }
+
+        Expression::UnaryOp { operand, .. } => infer_expr_type(operand, doc, store),
         
         Expression::FunctionCall { name, .. } => {
             if let Some(dot_pos) = name.rfind('.') {
@@ -140,6 +140,29 @@ fn check_exhaustiveness_in_expression(
                 );
             }
         }
+        // `scrutinee ? | pattern { .. }` - the form the parser produces
+        Expression::QuestionMatch { scrutinee, arms } => {
+            let arms = arms
+                .iter()
+                .map(|arm| PatternArm {
+                    pattern: arm.pattern.clone(),
+                    guard: arm.guard.clone(),
+                    body: arm.body.clone(),
+                })
+                .collect();
+            check_exhaustiveness_in_expression(
+                &Expression::PatternMatch {
+                    scrutinee: scrutinee.clone(),
+                    arms,
+                },
+                diagnostics,
+                content,
+                infer_expression_type_string,
+                find_pattern_match_position,
+                find_missing_variants,
+                depth,
+            );
+        }
         Expression::Block(stmts) => {
             check_pattern_exhaustiveness_with_depth(
                 stmts,
@@ -204,6 +227,17 @@ fn check_exhaustiveness_in_expression(
                 depth,
             );
         }
+        Expression::UnaryOp { operand, .. } => {
+            check_exhaustiveness_in_expression(
+                operand,
+                diagnostics,
+                content,
+                infer_expression_type_string,
+                find_pattern_match_position,
+                find_missing_variants,
+                depth,
+            );
+        }
         _ => {}
     }
 }
@@ -190,3 +190,41 @@ pub fn handle_workspace_symbol(
         error: None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::handle_workspace_symbol;
+    use crate::lsp::document_store::DocumentStore;
+    use lsp_server::{Request, RequestId};
+    use lsp_types::Url;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_workspace_symbol_matches_across_documents() {
+        let shapes = Url::parse("file:///tmp/shapes.zen").unwrap();
+        let solids = Url::parse("file:///tmp/solids.zen").unwrap();
+        let mut store = DocumentStore::new();
+        store.open(shapes.clone(), 1, "compute_area = (w: f64, h: f64) f64 {\n    return w * h\n}\n".to_string());
+        store.open(solids.clone(), 1, "compute_volume = (s: f64) f64 {\n    return s * s * s\n}\n".to_string());
+        let store = Arc::new(Mutex::new(store));
+
+        let req = Request {
+            id: RequestId::from(1),
+            method: "workspace/symbol".to_string(),
+            params: serde_json::json!({ "query": "COMPUTE_" }),
+        };
+        let response = handle_workspace_symbol(req, &store);
+        let symbols = response.result.unwrap();
+        let symbols = symbols.as_array().expect("result should be a list of symbols");
+
+        let location_of = |name: &str| {
+            symbols
+                .iter()
+                .find(|s| s["name"] == name)
+                .map(|s| s["location"]["uri"].as_str().unwrap_or("").to_string())
+        };
+        assert_eq!(location_of("compute_area"), Some(shapes.to_string()));
+        assert_eq!(location_of("compute_volume"), Some(solids.to_string()));
+        assert!(symbols.iter().all(|s| s["name"].as_str().unwrap_or("").contains("compute_")));
+    }
+}
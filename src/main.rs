@@ -1,16 +1,164 @@
+use inkwell::builder::BuilderError;
 use inkwell::context::Context;
 use inkwell::execution_engine::ExecutionEngine;
+use inkwell::module::Module;
+use inkwell::passes::PassBuilderOptions;
 use inkwell::targets::{CodeModel, FileType, RelocMode, Target, TargetMachine};
-use inkwell::OptimizationLevel;
+use inkwell::values::FunctionValue;
+use inkwell::{IntPredicate, OptimizationLevel};
+use std::collections::HashMap;
 use std::env;
-use std::io::{self, BufRead, Write};
+use std::io::{self, BufRead, IsTerminal, Read, Write};
 use std::path::Path;
 use std::process::Command;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
+use zen::ast::{AstType, Declaration, Function, Program};
 use zen::compiler::Compiler;
-use zen::error::{CompileError, Result};
-use zen::lexer::Lexer;
+use zen::error::{render_diagnostic, CompileError, Result, Severity, Span};
+use zen::lexer::{Lexer, Token};
 use zen::parser::Parser;
+use zen::well_known::well_known;
+
+/// Flags that change how a file is compiled
+struct BuildOptions {
+    /// Drop debug-only runtime checks (null dereference guards)
+    release: bool,
+    /// Report how long each compiler pass took
+    time_passes: bool,
+    /// How compile errors are reported on stderr
+    error_format: ErrorFormat,
+    /// Render human-readable errors with the source line, a caret and ANSI colors
+    color: bool,
+    /// Fail the build when any lint warning is reported
+    deny_warnings: bool,
+    /// Count function calls and print the counts when main returns
+    instrument_coverage: bool,
+    /// LLVM optimization level from `-O0`..`-O3`, applied to compiled output
+    opt_level: u8,
+    /// What `-o` writes: a linked executable, textual LLVM IR or assembly
+    emit: Emit,
+}
+
+/// Output of a `-o` build, chosen with `--emit-llvm` or `--emit-asm`
+#[derive(Clone, Copy, PartialEq)]
+enum Emit {
+    Executable,
+    LlvmIr,
+    Assembly,
+}
+
+/// Output format for compile errors, chosen with `--error-format=<human|json>`
+#[derive(Clone, Copy, PartialEq)]
+enum ErrorFormat {
+    Human,
+    Json,
+}
+
+impl BuildOptions {
+    /// The LLVM optimization level matching `opt_level`
+    fn llvm_opt_level(&self) -> OptimizationLevel {
+        match self.opt_level {
+            0 => OptimizationLevel::None,
+            1 => OptimizationLevel::Less,
+            2 => OptimizationLevel::Default,
+            _ => OptimizationLevel::Aggressive,
+        }
+    }
+
+    /// Turn a compile error from `file_path` into the error returned from `main`.
+    /// In JSON mode the error is written to stderr as one JSON object per line and
+    /// the process exits, so tooling never has to parse the human-readable text.
+    /// With color on, the error is drawn against `source` with a caret under its span.
+    fn compile_error(
+        &self,
+        file_path: &str,
+        source: &str,
+        stage: &str,
+        error: CompileError,
+    ) -> io::Error {
+        if self.print_compile_error(file_path, source, &error) {
+            std::process::exit(1);
+        }
+        io::Error::other(format!("{}: {}", stage, error))
+    }
+
+    /// Write a compile error to stderr as JSON or as a colored, caret-underlined
+    /// snippet. Returns false when plain text was asked for, leaving it to the caller.
+    fn print_compile_error(&self, file_path: &str, source: &str, error: &CompileError) -> bool {
+        if self.error_format == ErrorFormat::Json {
+            eprintln!(
+                "{}",
+                diagnostic_json(file_path, Severity::Error, &error.message(), error.span())
+            );
+            return true;
+        }
+        if self.color {
+            eprint!("{}", error.render(display_path(file_path), source, true));
+            return true;
+        }
+        false
+    }
+
+    /// Print the lint warnings for `program` on stderr.
+    /// With `--deny-warnings`, any warning fails the build.
+    fn report_warnings(
+        &self,
+        file_path: &str,
+        source: &str,
+        program: &zen::ast::Program,
+    ) -> io::Result<()> {
+        let warnings = zen::lints::check_program(program, source);
+        for warning in &warnings {
+            let span = warning.span.as_ref();
+            match self.error_format {
+                ErrorFormat::Json => eprintln!(
+                    "{}",
+                    diagnostic_json(file_path, Severity::Warning, &warning.message, span)
+                ),
+                ErrorFormat::Human => eprint!(
+                    "{}",
+                    render_diagnostic(
+                        Severity::Warning,
+                        &warning.message,
+                        span,
+                        display_path(file_path),
+                        source,
+                        self.color,
+                    )
+                ),
+            }
+        }
+
+        if self.deny_warnings && !warnings.is_empty() {
+            if self.error_format == ErrorFormat::Json {
+                std::process::exit(1);
+            }
+            return Err(io::Error::other(format!(
+                "{} warning(s) treated as errors (--deny-warnings)",
+                warnings.len()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Serialize a diagnostic as `{message, file, line, column, severity}`.
+/// Columns are reported 1-based to match the human-readable output.
+fn diagnostic_json(
+    file_path: &str,
+    severity: Severity,
+    message: &str,
+    span: Option<&Span>,
+) -> serde_json::Value {
+    serde_json::json!({
+        "message": message,
+        "file": display_path(file_path),
+        "line": span.map(|s| s.line),
+        "column": span.map(|s| s.column + 1),
+        "severity": severity.label(),
+    })
+}
 
 fn main() -> std::io::Result<()> {
     // Initialize LLVM
@@ -23,7 +171,95 @@ fn main() -> std::io::Result<()> {
     // the JIT backend with LLVM's target registry, causing segfaults.
     ExecutionEngine::link_in_mc_jit();
 
-    let args: Vec<String> = env::args().collect();
+    let mut release = false;
+    let mut time_passes = false;
+    let mut error_format = ErrorFormat::Human;
+    let mut color = None;
+    let mut deny_warnings = false;
+    let mut instrument_coverage = false;
+    let mut opt_level = 0;
+    let mut emit = Emit::Executable;
+    let mut iterations = DEFAULT_BENCH_ITERATIONS;
+    let mut dump_ast = false;
+    let mut dump_cfg = None;
+
+    // Flags are taken out wherever they appear; everything else keeps its order
+    let mut args: Vec<String> = Vec::new();
+    for arg in env::args() {
+        match arg.as_str() {
+            // --release drops debug-only runtime checks (null dereference guards)
+            "--release" => release = true,
+            // --time-passes reports the duration of each compiler pass on stderr
+            "--time-passes" => time_passes = true,
+            // --deny-warnings fails the build on any lint warning (useful in CI)
+            "--deny-warnings" => deny_warnings = true,
+            // --instrument-coverage reports how often each function ran when main returns
+            "--instrument-coverage" => instrument_coverage = true,
+            // -O0..-O3 sets how hard LLVM optimizes compiled output (default -O0 for fast builds)
+            "-O0" => opt_level = 0,
+            "-O1" => opt_level = 1,
+            "-O2" => opt_level = 2,
+            "-O3" => opt_level = 3,
+            // --emit-llvm / --emit-asm stop a -o build at a .ll or .s file instead of linking
+            "--emit-llvm" => emit = Emit::LlvmIr,
+            "--emit-asm" => emit = Emit::Assembly,
+            // --dump-ast prints the type-checked declarations instead of running the program
+            "--dump-ast" => dump_ast = true,
+            other => {
+                if let Some(level) = other.strip_prefix("-O") {
+                    eprintln!("Unknown optimization level '-O{}': expected -O0, -O1, -O2 or -O3", level);
+                    std::process::exit(2);
+                } else if let Some(format) = other.strip_prefix("--error-format=") {
+                    // --error-format=json reports compile errors as JSON objects for editors and CI
+                    error_format = match format {
+                        "human" => ErrorFormat::Human,
+                        "json" => ErrorFormat::Json,
+                        _ => {
+                            eprintln!("Unknown error format '{}': expected 'human' or 'json'", format);
+                            std::process::exit(2);
+                        }
+                    };
+                } else if let Some(mode) = other.strip_prefix("--color=") {
+                    // --color=<auto|always|never> controls caret-underlined, colored errors;
+                    // auto enables them only when stderr is a terminal
+                    color = match mode {
+                        "auto" => None,
+                        "always" => Some(true),
+                        "never" => Some(false),
+                        _ => {
+                            eprintln!("Unknown color mode '{}': expected 'auto', 'always' or 'never'", mode);
+                            std::process::exit(2);
+                        }
+                    };
+                } else if let Some(n) = other.strip_prefix("--iterations=") {
+                    // --iterations=<n> sets how many timed runs `zen bench` makes of each benchmark
+                    iterations = match n.parse::<u32>() {
+                        Ok(n) if n > 0 => n,
+                        _ => {
+                            eprintln!("Invalid iteration count '{}': expected a positive integer", n);
+                            std::process::exit(2);
+                        }
+                    };
+                } else if let Some(function) = other.strip_prefix("--dump-cfg=") {
+                    // --dump-cfg=<function> prints that function's control-flow graph as DOT
+                    dump_cfg = Some(function.to_string());
+                } else {
+                    args.push(arg);
+                }
+            }
+        }
+    }
+
+    let options = BuildOptions {
+        release,
+        time_passes,
+        error_format,
+        color: color.unwrap_or_else(|| io::stderr().is_terminal()),
+        deny_warnings,
+        instrument_coverage,
+        opt_level,
+        emit,
+    };
 
     match args.len() {
         1 => {
@@ -37,17 +273,31 @@ fn main() -> std::io::Result<()> {
                 print_usage();
                 return Ok(());
             }
-            // Compile and run the file
-            run_file(arg)?;
-        }
-        3 | 4 => {
-            // Multiple arguments - check for -o flag
-            if args.contains(&"-o".to_string()) {
-                compile_file(&args)?;
-            } else {
-                print_usage();
+            if dump_ast {
+                dump_ast_file(arg)?;
+                return Ok(());
+            }
+            if let Some(function) = &dump_cfg {
+                dump_cfg_file(arg, function, &options)?;
                 return Ok(());
             }
+            // Compile and run the file
+            run_file(arg, &options)?;
+        }
+        3 if args[1] == "watch" => {
+            watch_file(&args[2], &options)?;
+        }
+        3 if args[1] == "ir" => {
+            print_ir_file(&args[2], &options)?;
+        }
+        3 if args[1] == "test" => {
+            test_file(&args[2], &options)?;
+        }
+        3 if args[1] == "bench" => {
+            bench_file(&args[2], iterations, &options)?;
+        }
+        _ if args.contains(&"-o".to_string()) => {
+            compile_file(&args, &options)?;
         }
         _ => {
             print_usage();
@@ -64,14 +314,35 @@ fn print_usage() {
     println!("Usage:");
     println!("  zen                           Start interactive REPL");
     println!("  zen <file.zen>                Compile and run a Zen file");
+    println!("  zen - | zen --stdin           Compile and run a program read from stdin");
     println!("  zen <file.zen> -o <output>    Compile to executable (output in target/)");
     println!("  zen -o <output> <file.zen>    Compile to executable (output in target/)");
+    println!("  zen a.zen b.zen -o <output>   Compile several files into one executable");
+    println!("  zen watch <file.zen>          Recompile a Zen file whenever it changes");
+    println!("  zen ir <file.zen>             Print the LLVM IR generated for a Zen file");
+    println!("  zen test <file.zen>           Run the test_* functions in a Zen file");
+    println!("  zen bench <file.zen>          Time the bench_* functions in a Zen file");
     println!("  zen --help                    Show this help message");
     println!();
+    println!("Options:");
+    println!("  --release                     Omit debug runtime checks (null dereference guards)");
+    println!("  --dump-ast                    Print declarations with inferred types and exit");
+    println!("  --dump-cfg=<function>         Print a function's control-flow graph in DOT format and exit");
+    println!("  --time-passes                 Report the time spent in each compiler pass");
+    println!("  --error-format=json           Report compile errors as JSON objects on stderr");
+    println!("  --color=<auto|always|never>   Underline errors in the source with colors (auto: on a terminal)");
+    println!("  --deny-warnings               Treat warnings (such as unused variables) as errors");
+    println!("  --instrument-coverage         Print how many times each function ran when main returns");
+    println!("  --iterations=<n>              Timed runs per benchmark for zen bench (default 100)");
+    println!("  -O0, -O1, -O2, -O3            Optimization level for -o and zen ir (default -O0)");
+    println!("  --emit-llvm                   With -o, write LLVM IR to <output>.ll instead of linking");
+    println!("  --emit-asm                    With -o, write assembly to <output>.s instead of linking");
+    println!();
     println!("Examples:");
     println!("  zen                           # Start REPL");
     println!("  zen hello.zen                 # Run hello.zen file");
     println!("  zen hello.zen -o hello        # Compile to target/hello");
+    println!("  echo 'main = () i32 {{ 0 }}' | zen -   # Run a program from stdin");
 }
 
 fn run_repl() -> std::io::Result<()> {
@@ -82,18 +353,22 @@ fn run_repl() -> std::io::Result<()> {
     println!();
 
     let context = Context::create();
-    let mut compiler = Compiler::new(&context);
+    let compiler = Compiler::new(&context);
+    let mut session = ReplSession::default();
 
     let stdin = io::stdin();
     let mut stdin = stdin.lock();
     let mut stdout = io::stdout();
 
+    // Lines of a definition or block that is still open
+    let mut pending = String::new();
+
     loop {
-        print!("zen> ");
+        print!("{}", if pending.is_empty() { "zen> " } else { "...> " });
         stdout.flush()?;
 
-        let mut input = String::new();
-        let bytes_read = stdin.read_line(&mut input)?;
+        let mut line = String::new();
+        let bytes_read = stdin.read_line(&mut line)?;
 
         // Handle EOF (no bytes read)
         if bytes_read == 0 {
@@ -101,76 +376,110 @@ fn run_repl() -> std::io::Result<()> {
             break;
         }
 
-        let input = input.trim();
-
-        match input {
-            "exit" | "quit" => {
-                println!("Goodbye! 👋");
-                break;
-            }
-            "help" => {
-                print_repl_help();
-                continue;
-            }
-            "clear" => {
-                // Clear screen (simple version)
-                print!("\x1B[2J\x1B[1;1H");
-                stdout.flush()?;
-                continue;
+        if pending.is_empty() {
+            match line.trim() {
+                "exit" | "quit" => {
+                    println!("Goodbye! 👋");
+                    break;
+                }
+                "help" => {
+                    print_repl_help();
+                    continue;
+                }
+                "clear" => {
+                    // Clear screen (simple version)
+                    print!("\x1B[2J\x1B[1;1H");
+                    stdout.flush()?;
+                    continue;
+                }
+                "" => continue,
+                _ => {}
             }
-            "" => continue,
-            _ => {
-                // Parse and execute the input
-                match execute_zen_code(&mut compiler, input) {
-                    Ok(result) => {
-                        if let Some(value) = result {
-                            println!("=> {}", value);
+        }
+
+        // Keep reading until every bracket is closed; a blank line submits anyway
+        let force_submit = !pending.is_empty() && line.trim().is_empty();
+        pending.push_str(&line);
+        if !force_submit && open_bracket_depth(&pending) > 0 {
+            continue;
+        }
+
+        let input = std::mem::take(&mut pending);
+        if let Err(e) = session.eval(&compiler, input.trim()) {
+            println!("❌ Error: {}", e);
+        }
+    }
+
+    Ok(())
+}
+
+/// How many more `{`, `(` and `[` than closing brackets `input` has, not
+/// counting any inside string literals or `//` comments
+fn open_bracket_depth(input: &str) -> i32 {
+    let mut depth = 0;
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                // Skip to the closing quote, stepping over escapes
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
                         }
+                        '"' => break,
+                        _ => {}
                     }
-                    Err(e) => {
-                        println!("❌ Error: {}", e);
+                }
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
                     }
                 }
             }
+            '{' | '(' | '[' => depth += 1,
+            '}' | ')' | ']' => depth -= 1,
+            _ => {}
         }
     }
-
-    Ok(())
+    depth
 }
 
-fn run_file(file_path: &str) -> std::io::Result<()> {
-    let source = std::fs::read_to_string(file_path).map_err(|e| {
-        io::Error::new(
-            io::ErrorKind::NotFound,
-            format!("Failed to read file: {}", e),
-        )
-    })?;
+fn run_file(file_path: &str, options: &BuildOptions) -> std::io::Result<()> {
+    let source = read_source(file_path)?;
 
     let context = Context::create();
-    let compiler = Compiler::new(&context);
+    let compiler = Compiler::new(&context)
+        .with_debug_checks(!options.release)
+        .with_coverage(options.instrument_coverage);
 
-    let lexer = Lexer::new(&source);
-    let mut parser = Parser::new(lexer);
-    let program = parser
-        .parse_program()
-        .map_err(|e| io::Error::other(format!("Parse error: {}", e)))?;
+    let mut passes = Vec::new();
+    let program = parse_source(&source, options.time_passes.then_some(&mut passes))
+        .map_err(|e| options.compile_error(file_path, &source, "Parse error", e))?;
+    options.report_warnings(file_path, &source, &program)?;
 
     let module = compiler
         .get_module(&program)
-        .map_err(|e| io::Error::other(format!("Compilation error: {}", e)))?;
+        .map_err(|e| options.compile_error(file_path, &source, "Compilation error", e))?;
 
-    let execution_engine = module
-        .create_jit_execution_engine(OptimizationLevel::None)
-        .map_err(|e| io::Error::other(format!("Failed to create execution engine: {}", e)))?;
-
-    // Map __c_lib_mkdir to the actual mkdir symbol from libc
-    // This is needed because we use __c_lib_mkdir internally to avoid name collision
-    // with the Zen stdlib mkdir function
-    if let Some(mkdir_fn) = module.get_function("__c_lib_mkdir") {
-        let mkdir_ptr = libc::mkdir as *const ();
-        execution_engine.add_global_mapping(&mkdir_fn, mkdir_ptr as usize);
+    if options.time_passes {
+        passes.extend(compiler.pass_timings());
+        print_pass_timings(&passes);
     }
 
+    // A `Result` can't come back through run_function, so call main via a wrapper
+    // that reduces it to its discriminant. It must exist before the JIT compiles the module.
+    let result_main_wrapper = match module.get_function("main") {
+        Some(main_fn) if main_fn.get_type().get_return_type().is_some_and(|t| t.is_struct_type()) => {
+            Some(add_result_wrapper(&context, &module, main_fn, "__zen_result_main")?)
+        }
+        _ => None,
+    };
+
+    let execution_engine = create_jit(&module)?;
+
     let exit_code = match execution_engine.get_function_value("main") {
         Ok(main_fn) => {
             let main_type = main_fn.get_type();
@@ -180,22 +489,9 @@ fn run_file(file_path: &str) -> std::io::Result<()> {
                 if ret_type.is_int_type() {
                     let result = unsafe { execution_engine.run_function(main_fn, &[]) };
                     result.as_int(true) as i32
-                } else if ret_type.is_struct_type() {
-                    eprintln!("Warning: main() returns Result<T,E> which is not fully supported in JIT mode");
-                    eprintln!("The function will execute but the Result value cannot be extracted");
-
-                    unsafe {
-                        match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                            execution_engine.run_function(main_fn, &[])
-                        })) {
-                            Ok(_) => 0,
-                            Err(_) => {
-                                eprintln!("Error: Cannot execute main() with Result<T,E> return type in JIT mode");
-                                eprintln!("Consider using 'void' or 'i32' as the return type");
-                                1
-                            }
-                        }
-                    }
+                } else if let Some(wrapper) = result_main_wrapper {
+                    let result = unsafe { execution_engine.run_function(wrapper, &[]) };
+                    result.as_int(true) as i32
                 } else if ret_type.is_float_type() {
                     unsafe { execution_engine.run_function(main_fn, &[]) };
                     0
@@ -224,79 +520,495 @@ fn run_file(file_path: &str) -> std::io::Result<()> {
     Ok(())
 }
 
-fn compile_file(args: &[String]) -> std::io::Result<()> {
-    // Parse arguments
-    let (input_file, output_file_raw) = if args[1] == "-o" {
-        (&args[3], &args[2])
-    } else if args[2] == "-o" {
-        (&args[1], &args[3])
-    } else {
-        print_usage();
+/// Add a function `name` that calls `function`, which returns a `Result<T, E>` laid
+/// out as `{ discriminant, payload }`, and returns 0 for `Ok` or 1 for `Err`
+fn add_result_wrapper<'ctx>(
+    context: &'ctx Context,
+    module: &Module<'ctx>,
+    function: FunctionValue<'ctx>,
+    name: &str,
+) -> std::io::Result<FunctionValue<'ctx>> {
+    let function_name = function.get_name().to_string_lossy().into_owned();
+    let i32_type = context.i32_type();
+    let wrapper = module.add_function(name, i32_type.fn_type(&[], false), None);
+    let builder = context.create_builder();
+    builder.position_at_end(context.append_basic_block(wrapper, "entry"));
+
+    let build_error = |e: BuilderError| io::Error::other(format!("Failed to wrap {}: {}", function_name, e));
+    let result = builder
+        .build_call(function, &[], "result")
+        .map_err(build_error)?
+        .try_as_basic_value()
+        .left()
+        .ok_or_else(|| io::Error::other(format!("{}() returned no value", function_name)))?
+        .into_struct_value();
+    let discriminant = builder
+        .build_extract_value(result, 0, "discriminant")
+        .map_err(build_error)?;
+    if !discriminant.is_int_value() {
+        return Err(io::Error::other(format!("{}() returned a struct that is not a Result", function_name)));
+    }
+    let discriminant = discriminant.into_int_value();
+    let is_err = builder
+        .build_int_compare(IntPredicate::NE, discriminant, discriminant.get_type().const_zero(), "is_err")
+        .map_err(build_error)?;
+    let exit_code = builder.build_int_z_extend(is_err, i32_type, "exit_code").map_err(build_error)?;
+    builder.build_return(Some(&exit_code)).map_err(build_error)?;
+    Ok(wrapper)
+}
+
+/// Create a JIT execution engine for `module`
+fn create_jit<'ctx>(module: &Module<'ctx>) -> std::io::Result<ExecutionEngine<'ctx>> {
+    let execution_engine = module
+        .create_jit_execution_engine(OptimizationLevel::None)
+        .map_err(|e| io::Error::other(format!("Failed to create execution engine: {}", e)))?;
+
+    // Map __c_lib_mkdir to the actual mkdir symbol from libc
+    // This is needed because we use __c_lib_mkdir internally to avoid name collision
+    // with the Zen stdlib mkdir function
+    if let Some(mkdir_fn) = module.get_function("__c_lib_mkdir") {
+        let mkdir_ptr = libc::mkdir as *const ();
+        execution_engine.add_global_mapping(&mkdir_fn, mkdir_ptr as usize);
+    }
+
+    Ok(execution_engine)
+}
+
+/// Run every parameterless `test_*` function in `file_path` and report the results.
+/// A test passes when it returns normally with `0`, `true`, `Ok` or nothing; a non-zero,
+/// `false` or `Err` result, a panic and a failed assert all count as failures.
+fn test_file(file_path: &str, options: &BuildOptions) -> std::io::Result<()> {
+    let source = read_source(file_path)?;
+
+    let context = Context::create();
+    let compiler = Compiler::new(&context).with_debug_checks(!options.release);
+
+    let program = parse_source(&source, None)
+        .map_err(|e| options.compile_error(file_path, &source, "Parse error", e))?;
+    options.report_warnings(file_path, &source, &program)?;
+
+    let tests: Vec<&Function> = program
+        .functions()
+        .filter(|f| f.name.starts_with("test_") && f.args.is_empty())
+        .collect();
+    if tests.is_empty() {
+        println!("No test_* functions found in {}", display_path(file_path));
         return Ok(());
-    };
+    }
+    if let Some(test) = tests.iter().find(|f| !is_test_return_type(&f.return_type)) {
+        return Err(io::Error::other(format!(
+            "Test function {} returns {}; tests must return void, bool, an integer or a Result",
+            test.name, test.return_type
+        )));
+    }
 
-    // Ensure output goes to target directory if no directory specified
-    let output_file = if !output_file_raw.contains('/') {
-        format!("target/{}", output_file_raw)
+    let module = compiler
+        .get_module(&program)
+        .map_err(|e| options.compile_error(file_path, &source, "Compilation error", e))?;
+
+    // Like main, a `Result`-returning test is called through a wrapper that reduces
+    // it to its discriminant, added before the JIT compiles the module
+    let mut result_wrappers = HashMap::new();
+    for test in &tests {
+        if let Some(test_fn) = module.get_function(&test.name) {
+            if test_fn.get_type().get_return_type().is_some_and(|t| t.is_struct_type()) {
+                let wrapper_name = format!("__zen_result_{}", test.name);
+                let wrapper = add_result_wrapper(&context, &module, test_fn, &wrapper_name)?;
+                result_wrappers.insert(test.name.as_str(), wrapper);
+            }
+        }
+    }
+
+    let execution_engine = create_jit(&module)?;
+
+    let tests: Vec<&str> = tests.iter().map(|f| f.name.as_str()).collect();
+    println!("running {} tests", tests.len());
+    let mut failures = Vec::new();
+    for name in &tests {
+        let passed = run_test_function(&execution_engine, name, result_wrappers.get(name).copied())?;
+        println!("test {} ... {}", name, if passed { "ok" } else { "FAILED" });
+        if !passed {
+            failures.push(*name);
+        }
+    }
+
+    println!();
+    if !failures.is_empty() {
+        println!("failures:");
+        for name in &failures {
+            println!("    {}", name);
+        }
+        println!();
+    }
+    println!(
+        "test result: {}. {} passed; {} failed",
+        if failures.is_empty() { "ok" } else { "FAILED" },
+        tests.len() - failures.len(),
+        failures.len()
+    );
+
+    drop(execution_engine);
+
+    if !failures.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Return types whose value decides a test: nothing, a `bool`, an integer or a `Result`
+fn is_test_return_type(return_type: &AstType) -> bool {
+    match return_type {
+        AstType::Void | AstType::Bool => true,
+        AstType::Generic { name, .. } => well_known().is_result(name),
+        other => other.is_integer(),
+    }
+}
+
+/// Run one test function in a forked child, so a panic or failed assert
+/// (which end the process) only ends that test. `result_wrapper` is the
+/// function from `add_result_wrapper` for a `Result`-returning test.
+fn run_test_function<'ctx>(
+    execution_engine: &ExecutionEngine<'ctx>,
+    name: &str,
+    result_wrapper: Option<FunctionValue<'ctx>>,
+) -> std::io::Result<bool> {
+    let test_fn = execution_engine
+        .get_function_value(name)
+        .map_err(|e| io::Error::other(format!("Test function {} not found: {:?}", name, e)))?;
+
+    // Anything still buffered would otherwise be printed by both processes
+    io::stdout().flush()?;
+
+    match unsafe { libc::fork() } {
+        -1 => Err(io::Error::last_os_error()),
+        0 => {
+            let passed = if let Some(wrapper) = result_wrapper {
+                let result = unsafe { execution_engine.run_function(wrapper, &[]) };
+                result.as_int(true) == 0
+            } else {
+                match test_fn.get_type().get_return_type() {
+                    Some(ret_type) if ret_type.is_int_type() => {
+                        let result = unsafe { execution_engine.run_function(test_fn, &[]) };
+                        if ret_type.into_int_type().get_bit_width() == 1 {
+                            result.as_int(false) != 0
+                        } else {
+                            result.as_int(true) == 0
+                        }
+                    }
+                    _ => {
+                        unsafe { execution_engine.run_function(test_fn, &[]) };
+                        true
+                    }
+                }
+            };
+            // Flush the test's C stdio output; _exit skips Rust and LLVM teardown
+            unsafe {
+                libc::fflush(std::ptr::null_mut());
+                libc::_exit(if passed { 0 } else { 1 })
+            }
+        }
+        pid => {
+            let mut status = 0;
+            if unsafe { libc::waitpid(pid, &mut status, 0) } == -1 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(libc::WIFEXITED(status) && libc::WEXITSTATUS(status) == 0)
+        }
+    }
+}
+
+/// Timed runs of each benchmark unless `--iterations=<n>` is given
+const DEFAULT_BENCH_ITERATIONS: u32 = 100;
+
+/// Time every parameterless `bench_*` function in `file_path`, reporting the
+/// average and fastest of `iterations` runs after one untimed warm-up run
+fn bench_file(file_path: &str, iterations: u32, options: &BuildOptions) -> std::io::Result<()> {
+    let source = read_source(file_path)?;
+
+    let context = Context::create();
+    let compiler = Compiler::new(&context).with_debug_checks(!options.release);
+
+    let program = parse_source(&source, None)
+        .map_err(|e| options.compile_error(file_path, &source, "Parse error", e))?;
+    options.report_warnings(file_path, &source, &program)?;
+
+    let benches: Vec<&str> = program
+        .functions()
+        .filter(|f| f.name.starts_with("bench_") && f.args.is_empty())
+        .map(|f| f.name.as_str())
+        .collect();
+    if benches.is_empty() {
+        println!("No bench_* functions found in {}", display_path(file_path));
+        return Ok(());
+    }
+
+    let module = compiler
+        .get_module(&program)
+        .map_err(|e| options.compile_error(file_path, &source, "Compilation error", e))?;
+    let execution_engine = create_jit(&module)?;
+
+    println!("running {} benchmarks ({} iterations each)", benches.len(), iterations);
+    for name in &benches {
+        let bench_fn = execution_engine
+            .get_function_value(name)
+            .map_err(|e| io::Error::other(format!("Benchmark {} not found: {:?}", name, e)))?;
+
+        unsafe { execution_engine.run_function(bench_fn, &[]) };
+        let mut total = Duration::ZERO;
+        let mut fastest = Duration::MAX;
+        for _ in 0..iterations {
+            let start = Instant::now();
+            unsafe { execution_engine.run_function(bench_fn, &[]) };
+            let elapsed = start.elapsed();
+            total += elapsed;
+            fastest = fastest.min(elapsed);
+        }
+
+        println!(
+            "bench {:<32} avg {:>12?}  min {:>12?}",
+            name,
+            total / iterations,
+            fastest
+        );
+    }
+
+    drop(execution_engine);
+    Ok(())
+}
+
+/// How often `zen watch` checks the watched file for changes
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Compile `file_path`, then recompile each time its contents change.
+/// `ZEN_WATCH_MAX_BUILDS` stops the loop after that many builds (used by tests).
+fn watch_file(file_path: &str, options: &BuildOptions) -> std::io::Result<()> {
+    let max_builds: Option<usize> = env::var("ZEN_WATCH_MAX_BUILDS")
+        .ok()
+        .and_then(|v| v.parse().ok());
+
+    println!("👀 Watching {} (Ctrl+C to stop)", file_path);
+    let mut last_source: Option<String> = None;
+    let mut builds = 0;
+
+    loop {
+        // A file that is briefly missing mid-save is retried on the next poll
+        if let Ok(source) = std::fs::read_to_string(file_path) {
+            if last_source.as_ref() != Some(&source) {
+                builds += 1;
+                match check_source(&source, options) {
+                    Ok(()) => println!("✅ [build {}] {} compiled", builds, file_path),
+                    // JSON and colored errors go to stderr like any other build's
+                    Err((_, e)) if options.print_compile_error(file_path, &source, &e) => {
+                        println!("❌ [build {}] {} failed", builds, file_path)
+                    }
+                    Err((stage, e)) => println!("❌ [build {}] {}: {}", builds, stage, e),
+                }
+                io::stdout().flush()?;
+                last_source = Some(source);
+
+                if max_builds.is_some_and(|max| builds >= max) {
+                    return Ok(());
+                }
+            }
+        }
+        std::thread::sleep(WATCH_POLL_INTERVAL);
+    }
+}
+
+/// Run the full compile pipeline on `source` without executing or emitting anything
+fn check_source(
+    source: &str,
+    options: &BuildOptions,
+) -> std::result::Result<(), (&'static str, CompileError)> {
+    let context = Context::create();
+    let compiler = Compiler::new(&context).with_debug_checks(!options.release);
+    let program = parse_source(source, None).map_err(|e| ("Parse error", e))?;
+    compiler.get_module(&program).map_err(|e| ("Compilation error", e))?;
+    Ok(())
+}
+
+/// Whether `path` asks for the program to be read from standard input
+fn is_stdin_path(path: &str) -> bool {
+    path == "-" || path == "--stdin"
+}
+
+/// Name shown for `file_path` in error messages
+fn display_path(file_path: &str) -> &str {
+    if is_stdin_path(file_path) {
+        "<stdin>"
     } else {
-        output_file_raw.to_string()
-    };
+        file_path
+    }
+}
 
-    // Ensure target directory exists
-    if let Some(parent) = Path::new(&output_file).parent() {
-        std::fs::create_dir_all(parent)
-            .map_err(|e| io::Error::other(format!("Failed to create output directory: {}", e)))?;
+/// Read a program from `file_path`, or from stdin for `-` / `--stdin`
+fn read_source(file_path: &str) -> std::io::Result<String> {
+    if is_stdin_path(file_path) {
+        let mut source = String::new();
+        io::stdin()
+            .read_to_string(&mut source)
+            .map_err(|e| io::Error::other(format!("Failed to read stdin: {}", e)))?;
+        return Ok(source);
     }
 
-    // Read the source file
-    let source = std::fs::read_to_string(input_file).map_err(|e| {
+    std::fs::read_to_string(file_path).map_err(|e| {
         io::Error::new(
             io::ErrorKind::NotFound,
             format!("Failed to read file: {}", e),
         )
-    })?;
+    })
+}
+
+fn dump_ast_file(file_path: &str) -> std::io::Result<()> {
+    let source = read_source(file_path)?;
 
     let context = Context::create();
     let compiler = Compiler::new(&context);
 
-    // Parse the source
     let lexer = Lexer::new(&source);
     let mut parser = Parser::new(lexer);
     let program = parser
         .parse_program()
         .map_err(|e| io::Error::other(format!("Parse error: {}", e)))?;
 
-    // Get the LLVM module
+    let dump = compiler
+        .dump_typed_ast(&program)
+        .map_err(|e| io::Error::other(format!("Compilation error: {}", e)))?;
+    print!("{}", dump);
+
+    Ok(())
+}
+
+fn dump_cfg_file(file_path: &str, function: &str, options: &BuildOptions) -> std::io::Result<()> {
+    let source = read_source(file_path)?;
+
+    let context = Context::create();
+    let compiler = Compiler::new(&context)
+        .with_debug_checks(!options.release)
+        .with_coverage(options.instrument_coverage);
+
+    let program = parse_source(&source, None)
+        .map_err(|e| options.compile_error(file_path, &source, "Parse error", e))?;
+    let dot = compiler
+        .dump_cfg(&program, function)
+        .map_err(|e| options.compile_error(file_path, &source, "Compilation error", e))?;
+    print!("{}", dot);
+
+    Ok(())
+}
+
+/// Print the LLVM IR generated for `file_path` instead of running it
+fn print_ir_file(file_path: &str, options: &BuildOptions) -> std::io::Result<()> {
+    let source = read_source(file_path)?;
+
+    let context = Context::create();
+    let compiler = Compiler::new(&context)
+        .with_debug_checks(!options.release)
+        .with_coverage(options.instrument_coverage);
+
+    let program = parse_source(&source, None)
+        .map_err(|e| options.compile_error(file_path, &source, "Parse error", e))?;
     let module = compiler
         .get_module(&program)
-        .map_err(|e| io::Error::other(format!("Compilation error: {}", e)))?;
+        .map_err(|e| options.compile_error(file_path, &source, "Compilation error", e))?;
+    optimize_module(&module, &native_target_machine(options)?, options)?;
+    print!("{}", module.print_to_string().to_string());
 
-    // Debug: Print LLVM IR if DEBUG_LLVM is set
-    if std::env::var("DEBUG_LLVM").is_ok() {
-        eprintln!("LLVM IR:\n{}", module.print_to_string().to_string());
+    Ok(())
+}
+
+fn compile_file(args: &[String], options: &BuildOptions) -> std::io::Result<()> {
+    // Parse arguments: `-o <output>` anywhere, every other argument is an input file
+    let Some(output_pos) = args.iter().position(|a| a == "-o") else {
+        print_usage();
+        return Ok(());
+    };
+    let Some(output_file_raw) = args.get(output_pos + 1) else {
+        print_usage();
+        return Ok(());
+    };
+    let input_files: Vec<&str> = args
+        .iter()
+        .enumerate()
+        .skip(1)
+        .filter(|(i, _)| *i != output_pos && *i != output_pos + 1)
+        .map(|(_, a)| a.as_str())
+        .collect();
+    if input_files.is_empty() {
+        print_usage();
+        return Ok(());
     }
 
-    // Get target machine
-    let target_triple = TargetMachine::get_default_triple();
-    let target = Target::from_triple(&target_triple)
-        .map_err(|e| io::Error::other(format!("Failed to get target: {}", e)))?;
+    // Ensure output goes to target directory if no directory specified
+    let output_file = if !output_file_raw.contains('/') {
+        format!("target/{}", output_file_raw)
+    } else {
+        output_file_raw.to_string()
+    };
 
-    let target_machine = target
-        .create_target_machine(
-            &target_triple,
-            "generic",
-            "",
-            OptimizationLevel::Default,
-            RelocMode::Default,
-            CodeModel::Default,
-        )
-        .ok_or_else(|| io::Error::other("Failed to create target machine"))?;
+    // Ensure target directory exists
+    if let Some(parent) = Path::new(&output_file).parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| io::Error::other(format!("Failed to create output directory: {}", e)))?;
+    }
+
+    // Read the source files
+    let sources = input_files
+        .iter()
+        .map(|file| read_source(file))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    // IR and assembly output skip the object cache and the linker entirely
+    let extension = match options.emit {
+        Emit::Executable => None,
+        Emit::LlvmIr => Some("ll"),
+        Emit::Assembly => Some("s"),
+    };
+    if let Some(extension) = extension {
+        let out_path = if output_file.ends_with(&format!(".{}", extension)) {
+            output_file
+        } else {
+            format!("{}.{}", output_file, extension)
+        };
+        let mut passes = Vec::new();
+        emit_output(&input_files, &sources, &out_path, options, &mut passes)?;
+        println!("✅ Successfully wrote: {} (-O{})", out_path, options.opt_level);
+        if options.time_passes {
+            print_pass_timings(&passes);
+        }
+        return Ok(());
+    }
 
-    // Write object file
     let obj_path = format!("{}.o", output_file);
-    target_machine
-        .write_to_file(&module, FileType::Object, Path::new(&obj_path))
-        .map_err(|e| io::Error::other(format!("Failed to write object file: {}", e)))?;
+    let mut passes = Vec::new();
+
+    // Reuse the object file from an earlier compile of identical sources and imports
+    let cached_obj = object_cache_path(&input_files, &sources, options)?;
+    if cached_obj.exists() {
+        // Lints still run so warnings (and --deny-warnings) don't depend on the cache
+        parse_inputs(&input_files, &sources, options, None)?;
+
+        std::fs::copy(&cached_obj, &obj_path)
+            .map_err(|e| io::Error::other(format!("Failed to reuse cached object: {}", e)))?;
+        // Mark it recently used so eviction removes colder objects first
+        std::fs::File::options()
+            .append(true)
+            .open(&cached_obj)
+            .and_then(|f| f.set_modified(SystemTime::now()))
+            .ok();
+        println!("♻️  Cache hit: reusing {}", cached_obj.display());
+    } else {
+        emit_output(&input_files, &sources, &obj_path, options, &mut passes)?;
+
+        // A failed cache write only costs a recompile next time
+        if let Some(dir) = cached_obj.parent() {
+            std::fs::create_dir_all(dir).ok();
+        }
+        std::fs::copy(&obj_path, &cached_obj).ok();
+        if let Some(dir) = cached_obj.parent() {
+            evict_object_cache(dir);
+        }
+    }
 
     // Link with system libraries to create executable
     let mut cmd = Command::new("cc");
@@ -306,6 +1018,7 @@ fn compile_file(args: &[String]) -> std::io::Result<()> {
         .arg("-no-pie") // Disable PIE for compatibility
         .arg("-lm"); // Link math library
 
+    let start = Instant::now();
     let status = cmd
         .status()
         .map_err(|e| io::Error::other(format!("Failed to link: {}", e)))?;
@@ -313,32 +1026,413 @@ fn compile_file(args: &[String]) -> std::io::Result<()> {
     if !status.success() {
         return Err(io::Error::other("Linking failed"));
     }
+    passes.push(("linking", start.elapsed()));
 
     // Clean up object file
     std::fs::remove_file(&obj_path).ok();
 
-    println!("✅ Successfully compiled to: {}", output_file);
+    println!("✅ Successfully compiled to: {} (-O{})", output_file, options.opt_level);
+
+    if options.time_passes {
+        print_pass_timings(&passes);
+    }
 
     Ok(())
 }
 
-fn execute_zen_code(compiler: &mut Compiler, source: &str) -> Result<Option<String>> {
-    // Parse the source
-    let lexer = Lexer::new(source);
-    let mut parser = Parser::new(lexer);
-    let program = parser
-        .parse_program()
-        .map_err(|e| CompileError::InternalError(format!("Parse error: {}", e), None))?;
+/// Directory holding object files of earlier compiles, keyed by source hash
+const OBJECT_CACHE_DIR: &str = "target/.zen-cache";
+
+/// Cached objects not used for this long are evicted
+const OBJECT_CACHE_MAX_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Beyond this total size the least recently used cached objects are evicted
+const OBJECT_CACHE_MAX_BYTES: u64 = 256 * 1024 * 1024;
+
+/// Cache location for the object file compiled from `sources` with `options`.
+/// The key covers the compiler binary, the build flags and the text of every
+/// imported module (stdlib included), so a change to any of them invalidates it.
+fn object_cache_path(
+    input_files: &[&str],
+    sources: &[String],
+    options: &BuildOptions,
+) -> std::io::Result<std::path::PathBuf> {
+    let mut hasher = Fnv1a::new();
+    hasher.write_field(env!("CARGO_PKG_VERSION").as_bytes());
+    hasher.write_field(&compiler_build_identity());
+    hasher.write_field(&[
+        options.release as u8,
+        options.instrument_coverage as u8,
+        options.opt_level,
+    ]);
+    for source in sources {
+        hasher.write_field(source.as_bytes());
+    }
+
+    // Parse without lints; warnings are reported once by whichever path runs next
+    let mut program = Program::default();
+    for (file, source) in input_files.iter().zip(sources) {
+        let parsed = parse_source(source, None)
+            .map_err(|e| options.compile_error(file, source, "Parse error", e))?;
+        program
+            .merge(parsed)
+            .map_err(|e| options.compile_error(file, source, "Compilation error", e))?;
+    }
+    let (error_file, error_source) = match (input_files, sources) {
+        ([file], [source]) => (file.to_string(), source.as_str()),
+        _ => (input_files.join(", "), ""),
+    };
+    let context = Context::create();
+    let imported = Compiler::new(&context)
+        .imported_sources(&program)
+        .map_err(|e| options.compile_error(&error_file, error_source, "Compilation error", e))?;
+    for (module_path, source) in &imported {
+        hasher.write_field(module_path.as_bytes());
+        hasher.write_field(source.as_bytes());
+    }
+
+    Ok(Path::new(OBJECT_CACHE_DIR).join(format!("{:016x}.o", hasher.finish())))
+}
+
+/// Size and modification time of the running compiler binary. A rebuilt
+/// compiler can generate different code for the same sources without a version
+/// bump, so objects it did not produce must not be reused.
+fn compiler_build_identity() -> Vec<u8> {
+    let mut identity = Vec::new();
+    if let Ok(meta) = env::current_exe().and_then(std::fs::metadata) {
+        identity.extend(meta.len().to_le_bytes());
+        if let Some(since_epoch) = meta.modified().ok().and_then(|t| t.duration_since(UNIX_EPOCH).ok()) {
+            identity.extend(since_epoch.as_nanos().to_le_bytes());
+        }
+    }
+    identity
+}
+
+/// Bound the object cache in `dir`: drop objects unused for longer than
+/// `OBJECT_CACHE_MAX_AGE`, then the least recently used ones until the rest fit
+/// in `OBJECT_CACHE_MAX_BYTES`. Errors are ignored; eviction only limits disk use.
+fn evict_object_cache(dir: &Path) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let now = SystemTime::now();
+    let mut objects: Vec<_> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let meta = entry.metadata().ok()?;
+            let last_used = meta.modified().unwrap_or(now);
+            meta.is_file().then(|| (entry.path(), last_used, meta.len()))
+        })
+        .collect();
 
-    if program.declarations.is_empty() {
-        return Ok(None);
+    // Most recently used first, so the size budget goes to those
+    objects.sort_by_key(|(_, last_used, _)| std::cmp::Reverse(*last_used));
+    let mut kept_bytes = 0;
+    for (path, last_used, len) in objects {
+        let age = now.duration_since(last_used).unwrap_or_default();
+        if age > OBJECT_CACHE_MAX_AGE || kept_bytes + len > OBJECT_CACHE_MAX_BYTES {
+            std::fs::remove_file(path).ok();
+        } else {
+            kept_bytes += len;
+        }
+    }
+}
+
+/// 64-bit FNV-1a. Unlike `DefaultHasher` its output is fixed, so a cache key
+/// computed in one run matches the same key computed in the next.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    /// Hash `bytes` behind their length so adjacent fields can't run together
+    fn write_field(&mut self, bytes: &[u8]) {
+        self.write(&(bytes.len() as u64).to_le_bytes());
+        self.write(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Compile the input files down to a single object file at `out_path`, or to
+/// LLVM IR or assembly there when `options.emit` asks for it
+fn emit_output(
+    input_files: &[&str],
+    sources: &[String],
+    out_path: &str,
+    options: &BuildOptions,
+    passes: &mut Vec<(&'static str, Duration)>,
+) -> std::io::Result<()> {
+    let context = Context::create();
+    let compiler = Compiler::new(&context)
+        .with_debug_checks(!options.release)
+        .with_coverage(options.instrument_coverage);
+
+    // Parse the sources
+    let program = parse_inputs(
+        input_files,
+        sources,
+        options,
+        options.time_passes.then_some(&mut *passes),
+    )?;
+
+    // Once several files are merged, later errors can't be traced back to one of them
+    let (error_file, error_source) = match (input_files, sources) {
+        ([file], [source]) => (file.to_string(), source.as_str()),
+        _ => (input_files.join(", "), ""),
+    };
+
+    // Get the LLVM module
+    let module = compiler
+        .get_module(&program)
+        .map_err(|e| options.compile_error(&error_file, error_source, "Compilation error", e))?;
+    passes.extend(compiler.pass_timings());
+
+    // Debug: Print LLVM IR if DEBUG_LLVM is set
+    if std::env::var("DEBUG_LLVM").is_ok() {
+        eprintln!("LLVM IR:\n{}", module.print_to_string().to_string());
+    }
+
+    // The C runtime reads main's return value as an int, so a `Result`-returning
+    // main is renamed and called from a `main` that returns its discriminant
+    if let Some(main_fn) = module.get_function("main") {
+        if main_fn.get_type().get_return_type().is_some_and(|t| t.is_struct_type()) {
+            main_fn.as_global_value().set_name("__zen_user_main");
+            add_result_wrapper(&context, &module, main_fn, "main")?;
+        }
+    }
+
+    let target_machine = native_target_machine(options)?;
+
+    let start = Instant::now();
+    optimize_module(&module, &target_machine, options)?;
+    if options.opt_level > 0 {
+        passes.push(("optimization", start.elapsed()));
+    }
+
+    // Write the output file
+    let start = Instant::now();
+    match options.emit {
+        Emit::LlvmIr => {
+            module
+                .print_to_file(Path::new(out_path))
+                .map_err(|e| io::Error::other(format!("Failed to write LLVM IR: {}", e)))?;
+            passes.push(("IR emission", start.elapsed()));
+        }
+        Emit::Assembly => {
+            target_machine
+                .write_to_file(&module, FileType::Assembly, Path::new(out_path))
+                .map_err(|e| io::Error::other(format!("Failed to write assembly: {}", e)))?;
+            passes.push(("assembly emission", start.elapsed()));
+        }
+        Emit::Executable => {
+            target_machine
+                .write_to_file(&module, FileType::Object, Path::new(out_path))
+                .map_err(|e| io::Error::other(format!("Failed to write object file: {}", e)))?;
+            passes.push(("object emission", start.elapsed()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Target machine for the host, generating code at the `-O` level in `options`
+fn native_target_machine(options: &BuildOptions) -> std::io::Result<TargetMachine> {
+    let target_triple = TargetMachine::get_default_triple();
+    let target = Target::from_triple(&target_triple)
+        .map_err(|e| io::Error::other(format!("Failed to get target: {}", e)))?;
+
+    target
+        .create_target_machine(
+            &target_triple,
+            "generic",
+            "",
+            options.llvm_opt_level(),
+            RelocMode::Default,
+            CodeModel::Default,
+        )
+        .ok_or_else(|| io::Error::other("Failed to create target machine"))
+}
+
+/// Run LLVM's standard `default<On>` function and module pass pipeline over
+/// `module`; -O0 leaves it untouched
+fn optimize_module(module: &Module, target_machine: &TargetMachine, options: &BuildOptions) -> std::io::Result<()> {
+    if options.opt_level == 0 {
+        return Ok(());
+    }
+    module
+        .run_passes(
+            &format!("default<O{}>", options.opt_level),
+            target_machine,
+            PassBuilderOptions::create(),
+        )
+        .map_err(|e| io::Error::other(format!("Optimization failed: {}", e)))
+}
+
+/// Parse each input file, report its warnings and merge the declarations into
+/// one program. A name defined at the top level of two files is an error.
+fn parse_inputs(
+    input_files: &[&str],
+    sources: &[String],
+    options: &BuildOptions,
+    mut passes: Option<&mut Vec<(&'static str, Duration)>>,
+) -> std::io::Result<zen::ast::Program> {
+    let mut merged: Option<zen::ast::Program> = None;
+    for (file, source) in input_files.iter().zip(sources) {
+        let program = parse_source(source, passes.as_deref_mut())
+            .map_err(|e| options.compile_error(file, source, "Parse error", e))?;
+        options.report_warnings(file, source, &program)?;
+
+        match merged.as_mut() {
+            None => merged = Some(program),
+            Some(merged) => merged
+                .merge(program)
+                .map_err(|e| options.compile_error(file, source, "Compilation error", e))?,
+        }
+    }
+    Ok(merged.unwrap_or_default())
+}
+
+/// Parse `source`, recording lexing and parsing durations into `passes` when given
+fn parse_source(
+    source: &str,
+    passes: Option<&mut Vec<(&'static str, Duration)>>,
+) -> Result<zen::ast::Program> {
+    let parse = || Parser::new(Lexer::new(source)).parse_program();
+
+    let Some(passes) = passes else {
+        return parse();
+    };
+
+    // The parser pulls tokens on demand, so lexing is timed as a separate full scan
+    let start = Instant::now();
+    let mut lexer = Lexer::new(source);
+    while lexer.next_token() != Token::Eof {}
+    passes.push(("lexing", start.elapsed()));
+
+    let start = Instant::now();
+    let program = parse()?;
+    passes.push(("parsing", start.elapsed()));
+    Ok(program)
+}
+
+/// Print the duration of each compiler pass to stderr, followed by the total
+fn print_pass_timings(passes: &[(&'static str, Duration)]) {
+    eprintln!("Pass timings:");
+    for (name, duration) in passes {
+        eprintln!("  {:<18} {:>10.3} ms", name, duration.as_secs_f64() * 1000.0);
     }
+    let total: Duration = passes.iter().map(|(_, d)| *d).sum();
+    eprintln!("  {:<18} {:>10.3} ms", "total", total.as_secs_f64() * 1000.0);
+}
 
-    // Compile the program using LLVM backend
-    let llvm_ir = compiler.compile_llvm(&program)?;
+/// Name of the function each non-definition REPL input is compiled into
+const REPL_EVAL_FN: &str = "__repl_eval";
 
-    // Return just the LLVM IR
-    Ok(Some(llvm_ir))
+/// Definitions entered at the REPL so far. Every input is compiled together
+/// with them, so earlier functions, types and imports stay in scope.
+#[derive(Default)]
+struct ReplSession {
+    declarations: Vec<Declaration>,
+}
+
+impl ReplSession {
+    /// Define the items in `input`, or else run it as statements and print the
+    /// value of the last one
+    fn eval(&mut self, compiler: &Compiler, input: &str) -> Result<()> {
+        match Parser::new(Lexer::new(input)).parse_program() {
+            Ok(program) if !program.declarations.is_empty() => self.define(compiler, program.declarations),
+            _ => self.run(compiler, input),
+        }
+    }
+
+    /// Add `declarations`, each replacing an earlier one with the same name.
+    /// Nothing changes if the combined program no longer compiles.
+    fn define(&mut self, compiler: &Compiler, declarations: Vec<Declaration>) -> Result<()> {
+        let previous = self.declarations.clone();
+        for declaration in declarations {
+            if let Some(name) = declaration.declared_name() {
+                self.declarations.retain(|d| d.declared_name() != Some(name));
+            }
+            self.declarations.push(declaration);
+        }
+
+        let program = Program { declarations: self.declarations.clone(), statements: vec![] };
+        if let Err(e) = compiler.compile_llvm(&program) {
+            self.declarations = previous;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Run `input` as a function body, printing the value of its last statement
+    /// when it has one (void calls and assignments don't)
+    fn run(&self, compiler: &Compiler, input: &str) -> Result<()> {
+        let (statements, last) = split_last_statement(input);
+        let with_value = format!("{}\n__repl_value = {}\nio.println(\"=> ${{__repl_value}}\")", statements, last);
+        self.run_body(compiler, &with_value).or_else(|_| self.run_body(compiler, input))
+    }
+
+    fn run_body(&self, compiler: &Compiler, body: &str) -> Result<()> {
+        let source = format!("{{ io }} = @std\n{} = () void {{\n{}\n}}\n", REPL_EVAL_FN, body);
+        let wrapper = Parser::new(Lexer::new(&source)).parse_program()?;
+
+        // Keep the user's own `io` import if they made one
+        let mut declarations = self.declarations.clone();
+        let has_io = declarations.iter().any(|d| d.declared_name() == Some("io"));
+        declarations.extend(
+            wrapper
+                .declarations
+                .into_iter()
+                .filter(|d| !(has_io && d.declared_name() == Some("io"))),
+        );
+        let module = compiler.get_module(&Program { declarations, statements: vec![] })?;
+
+        let execution_engine =
+            create_jit(&module).map_err(|e| CompileError::InternalError(e.to_string(), None))?;
+        let eval_fn = execution_engine
+            .get_function_value(REPL_EVAL_FN)
+            .map_err(|e| CompileError::InternalError(format!("{:?}", e), None))?;
+        io::stdout().flush().ok();
+        unsafe {
+            execution_engine.run_function(eval_fn, &[]);
+            // Show the output before the next prompt rather than at exit
+            libc::fflush(std::ptr::null_mut());
+        }
+        Ok(())
+    }
+}
+
+/// Split REPL input at its last top-level `;` or newline into the statements
+/// before it and the final statement
+fn split_last_statement(input: &str) -> (&str, &str) {
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut split = None;
+    for (i, c) in input.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '(' | '{' | '[' if !in_string => depth += 1,
+            ')' | '}' | ']' if !in_string => depth -= 1,
+            ';' | '\n' if !in_string && depth == 0 => split = Some(i),
+            _ => {}
+        }
+    }
+    match split {
+        Some(i) => (&input[..i], &input[i + 1..]),
+        None => ("", input),
+    }
 }
 
 fn print_repl_help() {
@@ -347,9 +1441,34 @@ fn print_repl_help() {
     println!("  clear                   Clear the screen");
     println!("  exit, quit              Exit the REPL");
     println!();
+    println!("Definitions stay in scope for later lines; redefining a name replaces it.");
+    println!("Input continues on a '...>' line until every bracket is closed;");
+    println!("an empty line submits it as is.");
+    println!("Other input runs as statements and the value of the last one is printed.");
+    println!();
     println!("Zen code examples:");
-    println!("  main = () i32 {{ 42 }}");
     println!("  add = (a: i32, b: i32) i32 {{ a + b }}");
+    println!("  add(2, 3)");
     println!("  x := 10; y := 20; x + y");
     println!();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::open_bracket_depth;
+
+    #[test]
+    fn test_open_bracket_depth_counts_nested_brackets() {
+        assert_eq!(open_bracket_depth("main = () i32 {"), 1);
+        assert_eq!(open_bracket_depth("main = () i32 {\n    x ? | true { f(["), 4);
+        assert_eq!(open_bracket_depth("add = (a: i32, b: i32) i32 { return a + b }"), 0);
+        assert_eq!(open_bracket_depth("}"), -1);
+    }
+
+    #[test]
+    fn test_open_bracket_depth_ignores_strings_and_comments() {
+        assert_eq!(open_bracket_depth("io.println(\"{ (\")"), 0);
+        assert_eq!(open_bracket_depth("io.println(\"say \\\"}\\\" \") {"), 1);
+        assert_eq!(open_bracket_depth("f = () void { // closes later }"), 1);
+    }
+}
@@ -3,8 +3,36 @@ pub mod resolver;
 use crate::ast::{Declaration, Program};
 use crate::error::CompileError;
 use crate::parser::Parser;
-use std::collections::HashMap;
-use std::path::PathBuf;
+use resolver::ModuleResolver;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Project manifest looked up from the working directory upwards
+pub const MANIFEST_FILE: &str = "zen.toml";
+
+/// The parts of a `zen.toml` manifest that affect module resolution.
+/// Other sections and keys are ignored.
+///
+/// ```toml
+/// [modules]
+/// root = "src"         # directory project imports resolve from
+/// paths = ["vendor"]   # additional search paths
+/// ```
+///
+/// Paths are relative to the directory containing the manifest.
+#[derive(Debug, Default, Deserialize)]
+pub struct Manifest {
+    #[serde(default)]
+    pub modules: ModuleSettings,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ModuleSettings {
+    pub root: Option<PathBuf>,
+    #[serde(default)]
+    pub paths: Vec<PathBuf>,
+}
 
 /// Module system for Zen language
 pub struct ModuleSystem {
@@ -12,6 +40,12 @@ pub struct ModuleSystem {
     modules: HashMap<String, Program>,
     /// Search paths for modules
     search_paths: Vec<PathBuf>,
+    /// Modules whose imports are currently being loaded
+    loading: HashSet<String>,
+    /// Modules that were imported again while still loading (import cycles)
+    circular_imports: Vec<String>,
+    /// Source text of every module read from disk, keyed by module path
+    sources: BTreeMap<String, String>,
     /// Current working directory
     #[allow(dead_code)] // Stored for future use
     cwd: PathBuf,
@@ -56,11 +90,53 @@ impl ModuleSystem {
             search_paths.push(zen_path.join("lib"));
         }
 
-        ModuleSystem {
+        let mut module_system = ModuleSystem {
             modules: HashMap::new(),
             search_paths,
+            loading: HashSet::new(),
+            circular_imports: Vec::new(),
+            sources: BTreeMap::new(),
             cwd,
+        };
+
+        // A project manifest makes imports resolve relative to the project
+        if let Some(manifest) = Self::find_manifest(&module_system.cwd) {
+            if let Err(e) = module_system.load_manifest(&manifest) {
+                eprintln!("Warning: ignoring {}: {}", manifest.display(), e);
+            }
         }
+
+        module_system
+    }
+
+    /// Find the nearest `zen.toml` in `start` or one of its parent directories
+    pub fn find_manifest(start: &Path) -> Option<PathBuf> {
+        start
+            .ancestors()
+            .map(|dir| dir.join(MANIFEST_FILE))
+            .find(|path| path.is_file())
+    }
+
+    /// Read the manifest at `manifest_path` and search its module root and paths
+    /// before any default location
+    pub fn load_manifest(&mut self, manifest_path: &Path) -> Result<(), CompileError> {
+        let text = std::fs::read_to_string(manifest_path).map_err(|e| {
+            CompileError::FileNotFound(manifest_path.display().to_string(), Some(e.to_string()))
+        })?;
+        let manifest: Manifest = toml::from_str(&text).map_err(|e| {
+            CompileError::FileError(format!("Invalid {}: {}", manifest_path.display(), e))
+        })?;
+
+        let project_dir = manifest_path.parent().unwrap_or_else(|| Path::new("."));
+        let project_paths: Vec<PathBuf> = manifest
+            .modules
+            .root
+            .iter()
+            .chain(&manifest.modules.paths)
+            .map(|path| project_dir.join(path))
+            .collect();
+        self.search_paths.splice(0..0, project_paths);
+        Ok(())
     }
 
     /// Add a search path for modules
@@ -76,6 +152,15 @@ impl ModuleSystem {
             return Ok(&self.modules[module_path]);
         }
 
+        // Re-entering a module that is still loading its imports means the imports
+        // form a cycle. Hand back an empty placeholder instead of recursing forever;
+        // the outer load replaces it with the real program once it finishes.
+        if self.loading.contains(module_path) {
+            eprintln!("Warning: circular import of module '{}'", module_path);
+            self.circular_imports.push(module_path.to_string());
+            return Ok(self.modules.entry(module_path.to_string()).or_default());
+        }
+
         // Handle @std and std. modules - try to load actual stdlib files
         if module_path.starts_with("@std") || module_path.starts_with("std.") {
             // Extract module name from path
@@ -134,17 +219,9 @@ impl ModuleSystem {
                     )
                 })?;
 
-                for decl in &program.declarations {
-                    if let Declaration::ModuleImport {
-                        alias: _,
-                        module_path: import_path,
-                        ..
-                    } = decl
-                    {
-                        self.load_module(import_path)?;
-                    }
-                }
+                self.load_imports(module_path, &program)?;
 
+                self.sources.insert(module_path.to_string(), source);
                 self.modules.insert(module_path.to_string(), program);
                 return Ok(&self.modules[module_path]);
             }
@@ -175,25 +252,47 @@ impl ModuleSystem {
         })?;
 
         // Process imports in the loaded module
-        let processed_program = program.clone();
-        for decl in &program.declarations {
-            if let Declaration::ModuleImport {
-                alias: _,
-                module_path: import_path,
-                ..
-            } = decl
-            {
-                // Recursively load imported modules
-                self.load_module(import_path)?;
-            }
-        }
+        self.load_imports(module_path, &program)?;
 
         // Store the loaded module
-        self.modules
-            .insert(module_path.to_string(), processed_program.clone());
+        self.sources.insert(module_path.to_string(), source);
+        self.modules.insert(module_path.to_string(), program);
         Ok(&self.modules[module_path])
     }
 
+    /// Recursively load the modules imported by `program`, the parsed source of `module_path`
+    fn load_imports(&mut self, module_path: &str, program: &Program) -> Result<(), CompileError> {
+        self.loading.insert(module_path.to_string());
+        let result = program.declarations.iter().try_for_each(|decl| {
+            match decl {
+                Declaration::ModuleImport {
+                    module_path: import_path,
+                    ..
+                }
+                | Declaration::Export {
+                    from: Some(import_path),
+                    ..
+                } => {
+                    self.load_module(import_path)?;
+                }
+                _ => {}
+            }
+            Ok(())
+        });
+        self.loading.remove(module_path);
+        result
+    }
+
+    /// Source text of every module loaded from a file, keyed by module path
+    pub fn loaded_sources(&self) -> &BTreeMap<String, String> {
+        &self.sources
+    }
+
+    /// Module paths that were imported again while they were still loading
+    pub fn circular_imports(&self) -> &[String] {
+        &self.circular_imports
+    }
+
     /// Resolve a module path to a file path
     fn resolve_module_path(&self, module_path: &str) -> Result<PathBuf, CompileError> {
         // Convert module path (e.g., "std.io") to file path (e.g., "std/io.zen")
@@ -221,6 +320,39 @@ impl ModuleSystem {
         ))
     }
 
+    /// Symbols a loaded module makes available to its importers: its own public
+    /// items plus anything it re-exports with `@export { .. } from other`
+    pub fn exported_symbols(&self, module_path: &str) -> HashSet<String> {
+        let mut visited = HashSet::new();
+        self.collect_exports(module_path, &mut visited)
+    }
+
+    fn collect_exports(&self, module_path: &str, visited: &mut HashSet<String>) -> HashSet<String> {
+        let Some(program) = self.modules.get(module_path) else {
+            return HashSet::new();
+        };
+        // Facades that re-export each other must not recurse forever
+        if !visited.insert(module_path.to_string()) {
+            return HashSet::new();
+        }
+
+        let mut exports = ModuleResolver::extract_exports(program);
+        for decl in &program.declarations {
+            if let Declaration::Export {
+                symbols,
+                from: Some(source),
+            } = decl
+            {
+                if symbols.iter().any(|s| s == "*") {
+                    exports.extend(self.collect_exports(source, visited));
+                } else {
+                    exports.extend(symbols.iter().cloned());
+                }
+            }
+        }
+        exports
+    }
+
     /// Get all loaded modules
     pub fn get_modules(&self) -> &HashMap<String, Program> {
         &self.modules
@@ -274,7 +406,7 @@ impl ModuleSystem {
 
 #[cfg(test)]
 mod tests {
-    use crate::module_system::ModuleSystem;
+    use crate::module_system::{ModuleSystem, MANIFEST_FILE};
     use std::path::PathBuf;
 
     #[test]
@@ -284,6 +416,94 @@ mod tests {
         assert!(ms.modules.is_empty());
     }
 
+    #[test]
+    fn test_manifest_root_resolves_imports() {
+        let project = tempfile::tempdir().unwrap();
+        std::fs::create_dir(project.path().join("src")).unwrap();
+        std::fs::write(
+            project.path().join(MANIFEST_FILE),
+            "[package]\nname = \"shapes\"\n\n[modules]\nroot = \"src\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            project.path().join("src/geometry.zen"),
+            "area = (w: i32, h: i32) i32 {\n    return w * h\n}\n",
+        )
+        .unwrap();
+
+        // The manifest is found from anywhere inside the project
+        let nested = project.path().join("src");
+        let manifest = ModuleSystem::find_manifest(&nested).unwrap();
+        assert_eq!(manifest, project.path().join(MANIFEST_FILE));
+
+        let mut ms = ModuleSystem::new();
+        ms.load_manifest(&manifest).unwrap();
+        let module = ms.load_module("geometry").unwrap();
+        assert!(module.functions().any(|f| f.name == "area"));
+    }
+
+    #[test]
+    fn test_circular_imports_terminate() {
+        // Only search paths under a `stdlib` directory serve `@std.*` modules
+        let root = tempfile::tempdir().unwrap();
+        let stdlib = root.path().join("stdlib");
+        std::fs::create_dir(&stdlib).unwrap();
+        std::fs::write(
+            stdlib.join("cycle_left.zen"),
+            "{ right } = @std.cycle_right\n\nleft = () i32 {\n    return 1\n}\n",
+        )
+        .unwrap();
+        std::fs::write(
+            stdlib.join("cycle_right.zen"),
+            "{ left } = @std.cycle_left\n\nright = () i32 {\n    return 2\n}\n",
+        )
+        .unwrap();
+
+        let mut ms = ModuleSystem::new();
+        ms.add_search_path(stdlib);
+        let left = ms.load_module("@std.cycle_left").unwrap();
+        assert!(left.functions().any(|f| f.name == "left"));
+
+        assert_eq!(ms.circular_imports(), ["@std.cycle_left".to_string()]);
+        let right = &ms.get_modules()["@std.cycle_right"];
+        assert!(right.functions().any(|f| f.name == "right"));
+    }
+
+    #[test]
+    fn test_reexports_reach_importers_through_facade() {
+        let root = tempfile::tempdir().unwrap();
+        let stdlib = root.path().join("stdlib");
+        std::fs::create_dir(&stdlib).unwrap();
+        std::fs::write(
+            stdlib.join("shapes_impl.zen"),
+            "area = (w: i32, h: i32) i32 {\n    return w * h\n}\n",
+        )
+        .unwrap();
+        std::fs::write(
+            stdlib.join("shapes.zen"),
+            "@export { area } from @std.shapes_impl\n",
+        )
+        .unwrap();
+        std::fs::write(
+            root.path().join("app.zen"),
+            "{ shapes } = @std\n\nmain = () i32 {\n    return shapes.area(3, 4)\n}\n",
+        )
+        .unwrap();
+
+        let mut ms = ModuleSystem::new();
+        ms.add_search_path(root.path().to_path_buf());
+        ms.add_search_path(stdlib);
+        ms.load_module("app").unwrap();
+
+        // Loading the facade pulls in the module it re-exports from
+        assert!(ms.get_modules()["@std.shapes_impl"]
+            .functions()
+            .any(|f| f.name == "area"));
+        assert!(ms.exported_symbols("@std.shapes").contains("area"));
+        let merged = ms.merge_programs(crate::ast::Program::default());
+        assert!(merged.functions().any(|f| f.name == "area"));
+    }
+
     #[test]
     fn test_add_search_path() {
         let mut ms = ModuleSystem::new();
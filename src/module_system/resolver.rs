@@ -145,6 +145,7 @@ impl ModuleResolver {
                 self.resolve_expression(right)?;
                 Ok(())
             }
+            Expression::UnaryOp { operand, .. } => self.resolve_expression(operand),
             _ => Ok(()),
         }
     }
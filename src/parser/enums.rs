@@ -24,6 +24,25 @@ impl<'a> Parser<'a> {
             ));
         }
 
+        // Optional integer representation for FFI: `Name: repr(i32) A, B`
+        let repr = if matches!(&self.current_token, Token::Identifier(n) if n == "repr")
+            && self.peek_token == Token::Symbol('(')
+        {
+            self.next_token(); // consume 'repr'
+            self.next_token(); // consume '('
+            let repr_type = self.parse_type()?;
+            if !repr_type.is_integer() {
+                return Err(self.syntax_error(format!(
+                    "Enum representation must be an integer type, found `{}`",
+                    repr_type
+                )));
+            }
+            self.expect_symbol(')')?;
+            Some(repr_type)
+        } else {
+            None
+        };
+
         let mut variants = vec![];
         let mut first_variant = true;
 
@@ -79,12 +98,20 @@ impl<'a> Parser<'a> {
             )));
         }
 
+        if repr.is_some() && variants.iter().any(|v| v.payload.is_some()) {
+            return Err(self.syntax_error(format!(
+                "Enum `{}` has a repr but its variants carry payloads. Only payload-free enums can use repr",
+                name
+            )));
+        }
+
         Ok(EnumDefinition {
             name,
             type_params,
             variants,
             methods: Vec::new(),
             required_traits: Vec::new(), // Will be populated by .requires() statements
+            repr,
             span: Some(start_span),
         })
     }
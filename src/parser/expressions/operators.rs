@@ -1,6 +1,6 @@
 use crate::parser::core::Parser;
-use crate::ast::{BinaryOperator, Expression};
-use crate::error::Result;
+use crate::ast::{BinaryOperator, Expression, UnaryOperator};
+use crate::error::{CompileError, Result};
 use crate::lexer::Token;
 
 /// Parse expressions for use in patterns - doesn't allow `|` as bitwise OR
@@ -45,6 +45,9 @@ fn parse_binary_expression_impl(parser: &mut Parser, precedence: u8, allow_pipe_
                 break;
             }
         } else if let Token::Operator(op) = &parser.current_token {
+            if op == "++" || op == "--" {
+                return Err(step_operator_error(parser, op));
+            }
             let op_clone = op.clone();
             let next_prec = get_precedence(&op_clone);
             if next_prec > precedence {
@@ -113,23 +116,34 @@ fn parse_unary_expression(parser: &mut Parser) -> Result<Expression> {
         Token::Operator(op) if op == "-" => {
             parser.next_token();
             let expr = parse_unary_expression(parser)?;
-            // For now, represent unary minus as BinaryOp with 0 - expr
-            Ok(Expression::BinaryOp {
-                left: Box::new(Expression::Integer32(0)),
-                op: BinaryOperator::Subtract,
-                right: Box::new(expr),
+            // Negative literals stay literals; anything else negates at runtime
+            Ok(match expr {
+                Expression::Integer32(v) => Expression::Integer32(v.wrapping_neg()),
+                Expression::Integer64(v) => Expression::Integer64(v.wrapping_neg()),
+                Expression::Float64(v) => Expression::Float64(-v),
+                other => Expression::UnaryOp {
+                    op: UnaryOperator::Negate,
+                    operand: Box::new(other),
+                },
             })
         }
-        Token::Operator(op) if op == "!" => {
+        Token::Operator(op) if op == "~" => {
             parser.next_token();
             let expr = parse_unary_expression(parser)?;
-            // For now, represent logical not as a function call
-            Ok(Expression::FunctionCall {
-                name: "not".to_string(),
-                type_args: vec![],
-                args: vec![expr],
+            Ok(Expression::UnaryOp {
+                op: UnaryOperator::BitwiseNot,
+                operand: Box::new(expr),
             })
         }
+        Token::Symbol('!') => {
+            parser.next_token();
+            let expr = parse_unary_expression(parser)?;
+            Ok(Expression::UnaryOp {
+                op: UnaryOperator::Not,
+                operand: Box::new(expr),
+            })
+        }
+        Token::Operator(op) if op == "++" || op == "--" => Err(step_operator_error(parser, op)),
         // Address-of operator: &expr
         Token::Symbol('&') => {
             parser.next_token();
@@ -140,6 +154,14 @@ fn parse_unary_expression(parser: &mut Parser) -> Result<Expression> {
     }
 }
 
+/// `++`/`--` are statements only; using their value would be ambiguous
+fn step_operator_error(parser: &Parser, op: &str) -> CompileError {
+    parser.syntax_error(format!(
+        "'{}' can only be used as a statement on a variable (e.g. `x{}`)",
+        op, op
+    ))
+}
+
 fn parse_postfix_expression(parser: &mut Parser) -> Result<Expression> {
     super::primary::parse_primary_expression(parser)
 }
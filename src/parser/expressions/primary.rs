@@ -19,7 +19,10 @@ pub fn parse_primary_expression(parser: &mut Parser) -> Result<Expression> {
         Token::Identifier(id) if id == "break" => parse_break_expression(parser),
         Token::Identifier(id) if id == "continue" => parse_continue_expression(parser),
         Token::Identifier(id) if id == "return" => parse_return_expression(parser),
-        Token::Identifier(id) if id == "comptime" => parse_comptime_expression(parser),
+        // `comptime.builtin(...)` is an ordinary call chain on the `comptime` namespace
+        Token::Identifier(id) if id == "comptime" && parser.peek_token != Token::Symbol('.') => {
+            parse_comptime_expression(parser)
+        }
 
         // Literal expressions
         Token::Integer(value_str) => {
@@ -51,8 +54,8 @@ pub fn parse_primary_expression(parser: &mut Parser) -> Result<Expression> {
                 // void is a unit value - like () in other languages
                 return Ok(Expression::Unit);
             } else if name == "null" {
-                // null is an alias for None (Option::None)
-                return Ok(Expression::None);
+                // null pointer - distinct from Option.None
+                return Ok(Expression::Null);
             }
 
             // Check for Vec<T, size>() constructor vs Vec<T> { ... } struct literal vs Vec<T>.method()
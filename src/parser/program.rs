@@ -8,7 +8,8 @@ use crate::lexer::Token;
 use crate::well_known::well_known;
 
 impl<'a> Parser<'a> {
-    /// Parse an @export declaration
+    /// Parse an @export declaration, optionally re-exporting from another module:
+    /// `@export { foo } from @std.io`
     pub fn parse_export(&mut self) -> Result<Declaration> {
         self.next_token();
 
@@ -17,6 +18,7 @@ impl<'a> Parser<'a> {
             self.next_token();
             return Ok(Declaration::Export {
                 symbols: vec!["*".to_string()], // Special marker for "export all"
+                from: self.parse_export_source()?,
             });
         }
 
@@ -62,20 +64,75 @@ impl<'a> Parser<'a> {
 
         Ok(Declaration::Export {
             symbols: exported_symbols,
+            from: self.parse_export_source()?,
         })
     }
 
-    /// Parse a destructuring import: { name, name } = @std
+    /// Parse the optional `from @std.module` of a re-export
+    fn parse_export_source(&mut self) -> Result<Option<String>> {
+        if !matches!(&self.current_token, Token::Identifier(kw) if kw == "from") {
+            return Ok(None);
+        }
+        self.next_token();
+
+        let mut module_path = match &self.current_token {
+            Token::AtStd => "@std".to_string(),
+            Token::Identifier(name) => name.clone(),
+            _ => {
+                return Err(CompileError::SyntaxError(
+                    "Expected module path after 'from' in @export".to_string(),
+                    Some(self.current_span.clone()),
+                ))
+            }
+        };
+        self.next_token();
+
+        while self.current_token == Token::Symbol('.') {
+            self.next_token();
+            if let Token::Identifier(member) = &self.current_token {
+                module_path.push('.');
+                module_path.push_str(member);
+                self.next_token();
+            } else {
+                return Err(CompileError::SyntaxError(
+                    "Expected identifier after '.'".to_string(),
+                    Some(self.current_span.clone()),
+                ));
+            }
+        }
+
+        Ok(Some(module_path))
+    }
+
+    /// Parse a destructuring import: { name, name as alias } = @std
     pub fn parse_destructuring_import_declaration(&mut self) -> Result<Vec<Declaration>> {
         self.next_token();
-        let mut imported_names = vec![];
+        // (imported name, name it is bound to)
+        let mut imported_names: Vec<(String, String)> = vec![];
 
         // Parse imported names
         while self.current_token != Token::Symbol('}') && self.current_token != Token::Eof {
             if let Token::Identifier(name) = &self.current_token {
-                imported_names.push(name.clone());
+                let name = name.clone();
                 self.next_token();
 
+                // `name as alias` binds the import under a different name
+                let alias = if matches!(&self.current_token, Token::Identifier(kw) if kw == "as") {
+                    self.next_token();
+                    let Token::Identifier(alias) = &self.current_token else {
+                        return Err(CompileError::SyntaxError(
+                            format!("Expected alias name after '{} as'", name),
+                            Some(self.current_span.clone()),
+                        ));
+                    };
+                    let alias = alias.clone();
+                    self.next_token();
+                    alias
+                } else {
+                    name.clone()
+                };
+                imported_names.push((name, alias));
+
                 if self.current_token == Token::Symbol(',') {
                     self.next_token();
                 } else if self.current_token != Token::Symbol('}') {
@@ -132,8 +189,12 @@ impl<'a> Parser<'a> {
             // Create imports from the specified module
             // For destructuring imports like { Range } = @std.core.iterator,
             // we load the entire module, not module.name
+            if module_path != "@std" {
+                Self::reject_symbol_aliases(&imported_names, &module_path, &self.current_span)?;
+            }
+
             let mut declarations = vec![];
-            for name in imported_names {
+            for (name, alias) in imported_names {
                 let actual_module_path = if module_path == "@std" {
                     // Map common symbols to their actual module locations
                     let wk = well_known();
@@ -152,7 +213,7 @@ impl<'a> Parser<'a> {
                     module_path.clone()
                 };
                 declarations.push(Declaration::ModuleImport {
-                    alias: name.clone(),
+                    alias,
                     module_path: actual_module_path,
                     span: Some(self.current_span.clone()),
                 });
@@ -182,10 +243,12 @@ impl<'a> Parser<'a> {
                 // For destructuring imports like { Range } = @std.core.iterator,
                 // we need to load the entire module (not @std.core.iterator.Range)
                 // but alias the import as the imported name.
+                Self::reject_symbol_aliases(&imported_names, &module_path, &self.current_span)?;
+
                 let mut declarations = vec![];
-                for name in imported_names {
+                for (_, alias) in imported_names {
                     declarations.push(Declaration::ModuleImport {
-                        alias: name.clone(),
+                        alias,
                         module_path: module_path.clone(), // Load the whole module, not module.name
                         span: Some(self.current_span.clone()),
                     });
@@ -206,6 +269,25 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// `as` can only rename whole modules imported from `@std`; symbols pulled out of
+    /// a specific module keep their own names, so an alias there would never resolve
+    fn reject_symbol_aliases(
+        imported_names: &[(String, String)],
+        module_path: &str,
+        span: &crate::error::Span,
+    ) -> Result<()> {
+        match imported_names.iter().find(|(name, alias)| name != alias) {
+            Some((name, _)) => Err(CompileError::SyntaxError(
+                format!(
+                    "Cannot alias '{}' imported from {}: `as` only renames modules imported from @std",
+                    name, module_path
+                ),
+                Some(span.clone()),
+            )),
+            None => Ok(()),
+        }
+    }
+
     /// Check if the current position represents a module import after :=
     /// Returns true if this is @std, @std.xxx, or build.import pattern
     pub fn is_module_import_after_colon_assign(&mut self) -> bool {
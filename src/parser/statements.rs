@@ -2,7 +2,7 @@
 // Range and iterator loops have been removed in favor of functional iteration.
 use super::core::Parser;
 use super::statements_guard::{check_declaration_keyword_guard, check_statement_keyword_guard};
-use crate::ast::{Declaration, Expression, Program, Statement, VariableDeclarationType};
+use crate::ast::{BinaryOperator, Declaration, Expression, Program, Statement, VariableDeclarationType};
 use crate::error::{CompileError, Result};
 use crate::lexer::Token;
 
@@ -284,8 +284,11 @@ impl<'a> Parser<'a> {
                         self.next_token(); // Move past ':' to see what comes after
 
                         // Check what comes after ':'
-                        let is_enum = matches!(&self.current_token, Token::Identifier(_))
-                            || matches!(&self.current_token, Token::Symbol('.'));
+                        let is_packed_struct = matches!(&self.current_token, Token::Identifier(name) if name == "packed" || name == "union")
+                            && self.peek_token == Token::Symbol('{');
+                        let is_enum = !is_packed_struct
+                            && (matches!(&self.current_token, Token::Identifier(_))
+                                || matches!(&self.current_token, Token::Symbol('.')));
                         let is_function_or_external = matches!(&self.current_token, Token::Symbol('('));
                         let is_behavior = matches!(&self.current_token, Token::Identifier(name) if name == "behavior");
 
@@ -298,7 +301,9 @@ impl<'a> Parser<'a> {
                         };
 
                         // Check if it's a trait or struct (both start with '{')
-                        let (is_struct, is_trait) = if self.current_token == Token::Symbol('{') {
+                        let (is_struct, is_trait) = if is_packed_struct {
+                            (true, false)
+                        } else if self.current_token == Token::Symbol('{') {
                             let looks_like_trait = self.with_lookahead(|p| p.looks_like_trait());
                             if looks_like_trait { (false, true) } else { (true, false) }
                         } else {
@@ -799,6 +804,13 @@ impl<'a> Parser<'a> {
             }
             Token::Identifier(id) if id == "comptime" => {
                 let span = Some(self.current_span.clone());
+                // `comptime.assert(...)` runs in the interpreter like a one-statement block
+                if self.peek_token == Token::Symbol('.') {
+                    let expr = self.parse_expression()?;
+                    self.skip_optional_semicolon();
+                    let statements = vec![Statement::Expression { expr, span: span.clone() }];
+                    return Ok(Statement::ComptimeBlock { statements, span });
+                }
                 self.next_token(); // consume 'comptime'
                 if self.current_token != Token::Symbol('{') {
                     let expr = Expression::Comptime(Box::new(self.parse_expression()?));
@@ -846,6 +858,25 @@ impl<'a> Parser<'a> {
                         // Mutable type declaration: name :: T or name :: T = value
                         self.parse_variable_declaration()
                     }
+                    Token::Operator(op) if op == "++" || op == "--" => {
+                        // x++ / x-- desugar to x = x + 1 / x = x - 1
+                        let span = Some(self.current_span.clone());
+                        let name = _name.clone();
+                        self.next_token(); // consume identifier
+                        let op = if self.current_token == Token::Operator("++".to_string()) {
+                            BinaryOperator::Add
+                        } else {
+                            BinaryOperator::Subtract
+                        };
+                        self.next_token(); // consume '++' / '--'
+                        self.skip_optional_semicolon();
+                        let value = Expression::BinaryOp {
+                            left: Box::new(Expression::Identifier(name.clone())),
+                            op,
+                            right: Box::new(Expression::Integer32(1)),
+                        };
+                        Ok(Statement::VariableAssignment { name, value, span })
+                    }
                     Token::Symbol('.') | Token::Symbol('[') => {
                         // Could be member access or array indexing followed by assignment
                         let span = Some(self.current_span.clone());
@@ -1078,6 +1109,14 @@ impl<'a> Parser<'a> {
 
     /// Parse a comptime block: comptime { statements... }
     fn parse_comptime_block_declaration(&mut self) -> Result<Declaration> {
+        // Top-level `comptime.assert(...)` becomes a one-statement comptime block
+        if self.peek_token == Token::Symbol('.') {
+            let span = Some(self.current_span.clone());
+            let expr = self.parse_expression()?;
+            self.skip_optional_semicolon();
+            return Ok(Declaration::ComptimeBlock(vec![Statement::Expression { expr, span }]));
+        }
+
         self.next_token(); // consume 'comptime'
         if self.current_token != Token::Symbol('{') {
             return Err(CompileError::SyntaxError(
@@ -109,7 +109,7 @@ pub fn check_statement_keyword_guard(token: &Token, span: &Span) -> Option<Resul
 
             // Null/nil keywords
             "null" | "nil" | "nullptr" | "None" => Some(Err(CompileError::SyntaxError(
-                "Zen doesn't use 'null/nil/None'. Use Option type: .Some(value) or .None".to_string(),
+                "Zen doesn't use 'null/nil/None' for missing values. Use Option type: .Some(value) or .None ('null' is only a pointer value)".to_string(),
                 Some(span.clone()),
             ))),
 
@@ -17,6 +17,18 @@ impl<'a> Parser<'a> {
         // Expect and consume ':' for type definition
         self.expect_symbol(':')?;
 
+        // Optional layout modifier: `MyStruct: packed { ... }` or `MyUnion: union { ... }`
+        let modifier = match &self.current_token {
+            Token::Identifier(n) if (n == "packed" || n == "union") && self.peek_token == Token::Symbol('{') => {
+                let n = n.clone();
+                self.next_token(); // consume modifier
+                Some(n)
+            }
+            _ => None,
+        };
+        let is_packed = modifier.as_deref() == Some("packed");
+        let is_union = modifier.as_deref() == Some("union");
+
         // Check if they're trying to use enum syntax (comma-separated) for a struct
         if matches!(&self.current_token, Token::Identifier(_))
             || self.current_token == Token::Symbol('.')
@@ -57,6 +69,29 @@ impl<'a> Parser<'a> {
             // Field type
             let field_type = self.parse_type()?;
 
+            // Optional bitfield width: `flags: u8 : 3`
+            let bit_width = if self.try_consume_symbol(':') {
+                let width = match &self.current_token {
+                    Token::Integer(n) => n.parse::<u32>().ok(),
+                    _ => None,
+                };
+                let max_width = crate::ast::bit_size(&field_type).filter(|_| field_type.is_integer());
+                match (width, max_width) {
+                    (Some(w), Some(max)) if w > 0 && w <= max => {
+                        self.next_token();
+                        Some(w)
+                    }
+                    _ => {
+                        return Err(self.syntax_error(format!(
+                            "Bitfield '{}' needs an integer type and a width between 1 and the type's bit size",
+                            field_name
+                        )))
+                    }
+                }
+            } else {
+                None
+            };
+
             // Optional default value
             let default_value = if self.try_consume_operator("=") {
                 Some(self.parse_expression()?)
@@ -69,6 +104,7 @@ impl<'a> Parser<'a> {
                 type_: field_type,
                 is_mutable,
                 default_value,
+                bit_width,
             });
 
             // Comma separator (except for last field)
@@ -90,6 +126,8 @@ impl<'a> Parser<'a> {
             type_params,
             fields,
             methods,
+            is_packed,
+            is_union,
             span: Some(start_span),
         })
     }
@@ -71,6 +71,7 @@ impl StdlibTypeRegistry {
             // Collections
             "collections/vec.zen",
             "collections/hashmap.zen",
+            "collections/hashset.zen",
             "collections/set.zen",
             "collections/string.zen",
             "collections/queue.zen",
@@ -275,15 +276,25 @@ impl StdlibTypeRegistry {
         self.struct_types.get(name).cloned()
     }
 
-    /// Check if a type requires an allocator (has an 'allocator' field in its struct definition)
+    /// Check if a type requires an allocator: it has an 'allocator' field, or a field
+    /// whose type does (HashSet through its HashMap)
     pub fn requires_allocator(&self, type_name: &str) -> bool {
-        if let Some(struct_def) = self.structs.get(type_name) {
-            struct_def.fields.iter().any(|f| {
-                f.name == "allocator" || matches!(&f.type_, AstType::Generic { name, .. } if name == "Allocator")
-            })
-        } else {
-            false
+        self.requires_allocator_visiting(type_name, &mut Vec::new())
+    }
+
+    fn requires_allocator_visiting<'a>(&'a self, type_name: &'a str, visiting: &mut Vec<&'a str>) -> bool {
+        if visiting.contains(&type_name) {
+            return false;
         }
+        let Some(struct_def) = self.structs.get(type_name) else {
+            return false;
+        };
+        visiting.push(type_name);
+        struct_def.fields.iter().any(|f| {
+            f.name == "allocator"
+                || matches!(&f.type_, AstType::Generic { name, .. }
+                    if name == "Allocator" || self.requires_allocator_visiting(name, visiting))
+        })
     }
 
     /// Check if a type has a constructor that returns an instance of itself (e.g., HashMap.new())
@@ -69,6 +69,7 @@ impl<'a, 'prog> TypeInstantiator<'a, 'prog> {
                 type_: substitution.apply(&field.type_),
                 is_mutable: field.is_mutable,
                 default_value: field.default_value.clone(),
+                bit_width: field.bit_width,
             })
             .collect();
 
@@ -83,6 +84,8 @@ impl<'a, 'prog> TypeInstantiator<'a, 'prog> {
             type_params: Vec::new(),
             fields: instantiated_fields,
             methods: instantiated_methods,
+            is_packed: struct_def.is_packed,
+            is_union: struct_def.is_union,
             span: struct_def.span.clone(), // Preserve original span
         })
     }
@@ -123,6 +126,7 @@ impl<'a, 'prog> TypeInstantiator<'a, 'prog> {
             variants: instantiated_variants,
             methods: instantiated_methods,
             required_traits: enum_def.required_traits.clone(),
+            repr: enum_def.repr.clone(),
             span: enum_def.span.clone(), // Preserve original span
         })
     }
@@ -234,6 +238,10 @@ impl<'a, 'prog> TypeInstantiator<'a, 'prog> {
                 op: op.clone(),
                 right: Box::new(self.instantiate_expression(right, substitution)),
             },
+            Expression::UnaryOp { op, operand } => Expression::UnaryOp {
+                op: op.clone(),
+                operand: Box::new(self.instantiate_expression(operand, substitution)),
+            },
             Expression::StructLiteral { name, fields } => Expression::StructLiteral {
                 name: name.clone(),
                 fields: fields
@@ -228,6 +228,7 @@ impl Monomorphizer {
                 self.collect_from_expression(left);
                 self.collect_from_expression(right);
             }
+            Expression::UnaryOp { operand, .. } => self.collect_from_expression(operand),
             Expression::QuestionMatch { scrutinee, arms } => {
                 self.collect_from_expression(scrutinee);
                 for arm in arms {
@@ -10,6 +10,7 @@ pub fn check_function(checker: &mut TypeChecker, function: &Function) -> Result<
 
     // Set the expected return type for this function
     checker.set_function_return_type(Some(function.return_type.clone()));
+    checker.current_function = Some(function.name.clone());
 
     // Add function parameters to scope
     // TODO: Parse and handle mutable parameters (:: syntax)
@@ -54,6 +55,7 @@ pub fn check_function(checker: &mut TypeChecker, function: &Function) -> Result<
 
     // Clear the expected return type
     checker.set_function_return_type(None);
+    checker.current_function = None;
 
     checker.exit_scope();
     Ok(())
@@ -1,10 +1,32 @@
 //! Binary operation type inference
 
-use crate::ast::{AstType, BinaryOperator, Expression};
+use crate::ast::{AstType, BinaryOperator, Expression, UnaryOperator};
 use crate::error::{CompileError, Result};
 use crate::typechecker::TypeChecker;
 use super::helpers::is_string_type;
 
+/// Infer the type of a unary operation - the result has the operand's type
+pub fn infer_unary_op_type(
+    checker: &mut TypeChecker,
+    op: &UnaryOperator,
+    operand: &Expression,
+) -> Result<AstType> {
+    let operand_type = checker.infer_expression_type(operand)?;
+    let valid = match op {
+        UnaryOperator::Negate => operand_type.is_numeric(),
+        UnaryOperator::BitwiseNot => operand_type.is_integer(),
+        UnaryOperator::Not => matches!(operand_type, AstType::Bool),
+    };
+    if valid {
+        Ok(operand_type)
+    } else {
+        Err(CompileError::TypeError(
+            format!("Cannot apply {:?} to type {:?}", op, operand_type),
+            checker.get_current_span(),
+        ))
+    }
+}
+
 /// Infer the type of a binary operation
 pub fn infer_binary_op_type(
     checker: &mut TypeChecker,
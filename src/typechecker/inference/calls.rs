@@ -41,6 +41,10 @@ pub fn infer_function_call_type(
                 return result;
             }
 
+            if let Some(result) = intrinsics::check_core_intrinsic(checker, module, func, args) {
+                return result;
+            }
+
             if let Some(return_type) = checker.get_stdlib_function_type(module, func) {
                 return Ok(return_type.clone());
             }
@@ -88,12 +92,37 @@ pub fn infer_function_call_type(
     }
 }
 
+/// Return type of `math.<method>(args)` for functions with a float overload.
+///
+/// An argument that infers to f32 or f64 selects the float version (`abs` ->
+/// `fabs`, returning f64). Anything else, including an untyped integer literal,
+/// selects the integer version, so `math.abs(-5)` is i64.
+fn infer_math_overload_type(
+    checker: &mut TypeChecker,
+    method: &str,
+    args: &[Expression],
+) -> Result<Option<AstType>> {
+    let Some(float_name) = crate::intrinsics::math_float_overload(method) else {
+        return Ok(None);
+    };
+    let is_float = match args.first() {
+        Some(arg) => matches!(checker.infer_expression_type(arg)?, AstType::F32 | AstType::F64),
+        None => false,
+    };
+    let name = if is_float { float_name } else { method };
+    Ok(checker
+        .stdlib_functions
+        .get(&format!("{}::{}", crate::intrinsics::MATH_MODULE_PATH, name))
+        .map(|sig| sig.return_type.clone()))
+}
+
 /// Infer the return type of a method call
 pub fn infer_method_call_type(
     checker: &mut TypeChecker,
     object: &Expression,
     method: &str,
     type_args: &[AstType],
+    args: &[Expression],
 ) -> Result<AstType> {
     if let Expression::Identifier(name) = object {
         // Check for compiler intrinsics first (compiler.* or @builtin.*)
@@ -104,6 +133,10 @@ pub fn infer_method_call_type(
             }
         }
 
+        if let Some(result) = intrinsics::check_core_intrinsic(checker, name, method, args) {
+            return result;
+        }
+
         // Check for methods (Type.method style like String.len)
         if let Some(return_type) = checker.get_stdlib_method_type(name, method) {
             return Ok(return_type.clone());
@@ -114,6 +147,20 @@ pub fn infer_method_call_type(
             return Ok(return_type.clone());
         }
 
+        // C functions of an imported module are registered under its path:
+        // `{ math } = @std` then `math.atan2(y, x)` looks up "@std.math::atan2"
+        if let Some(module_path) = checker.module_imports.get(name).cloned() {
+            if module_path == crate::intrinsics::MATH_MODULE_PATH {
+                if let Some(return_type) = infer_math_overload_type(checker, method, args)? {
+                    return Ok(return_type);
+                }
+            }
+            let key = format!("{}::{}", module_path, method);
+            if let Some(sig) = checker.stdlib_functions.get(&key).filter(|sig| sig.is_external) {
+                return Ok(sig.return_type.clone());
+            }
+        }
+
         // Check for user-defined attached methods (like MyStruct.new)
         let full_method_name = format!("{}.{}", name, method);
         if let Some(func_sig) = checker.get_function_signatures().get(&full_method_name) {
@@ -211,6 +258,15 @@ pub fn infer_method_call_type(
         }
     }
 
+    // Built-in raw/mutable pointer methods: arithmetic keeps the pointer type
+    if object_type.is_mutable_ptr() || object_type.is_raw_ptr() {
+        match method {
+            "offset" => return Ok(object_type.clone()),
+            "is_null" | "is_not_null" => return Ok(AstType::Bool),
+            _ => {}
+        }
+    }
+
     // Pointer methods - check for Ptr<T>, MutPtr<T>, RawPtr<T> methods
     if let Some(inner) = object_type.ptr_inner() {
         if let Some(return_type) = method_types::infer_pointer_method_type(method, inner) {
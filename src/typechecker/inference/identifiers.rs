@@ -71,6 +71,18 @@ pub fn infer_identifier_type(checker: &mut TypeChecker, name: &str) -> Result<As
         }
     }
 
+    // Selective imports only bring the listed names into scope, so `{ io } = @std`
+    // followed by `math.sqrt(...)` must fail rather than reach the sibling module
+    if crate::intrinsics::is_importable_std_module(name) {
+        return Err(CompileError::ImportError(
+            format!(
+                "'{}' is not imported; add it to an import list such as `{{ {} }} = @std`",
+                name, name
+            ),
+            checker.get_current_span(),
+        ));
+    }
+
     Err(CompileError::UndeclaredVariable(
         name.to_string(),
         checker.get_current_span(),
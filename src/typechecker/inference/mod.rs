@@ -12,7 +12,7 @@ pub mod closures;
 
 // Re-export all public functions for backward compatibility
 pub use helpers::{extract_type_name, is_string_type};
-pub use binary_ops::{infer_binary_op_type, promote_numeric_types, types_comparable};
+pub use binary_ops::{infer_binary_op_type, infer_unary_op_type, promote_numeric_types, types_comparable};
 pub use member_access::{infer_member_type, infer_struct_field_type};
 pub use identifiers::infer_identifier_type;
 pub use calls::{infer_function_call_type, infer_method_call_type};
@@ -1,9 +1,12 @@
 //! Compiler intrinsics type checking
 //! Uses crate::intrinsics as the single source of truth for intrinsic types
 
-use crate::ast::AstType;
-use crate::error::Result;
+use crate::ast::{AstType, Expression};
+use crate::error::{CompileError, Result};
 use crate::intrinsics as compiler_intrinsics;
+use crate::stdlib_types::StdlibTypeRegistry;
+use crate::typechecker::inference::helpers::is_string_type;
+use crate::typechecker::TypeChecker;
 
 /// Check compiler intrinsic function calls and return their type
 /// Returns None if not a compiler intrinsic, otherwise returns Ok(type) or error
@@ -24,3 +27,75 @@ pub fn check_compiler_intrinsic(
 
     compiler_intrinsics::check_intrinsic_call(func, args_len)
 }
+
+/// Check the core functions implemented by the compiler rather than in core.zen:
+/// `core.assert(condition, message?)` and `core.offset_of(Struct, field)`.
+/// The module's import path is consulted too, so aliases of core match.
+pub fn check_core_intrinsic(
+    checker: &mut TypeChecker,
+    module: &str,
+    func: &str,
+    args: &[Expression],
+) -> Option<Result<AstType>> {
+    let is_core = module == "core"
+        || checker.module_imports.get(module).is_some_and(|path| path == "@std.core");
+    if !is_core {
+        return None;
+    }
+    match func {
+        "assert" => Some(check_core_assert(checker, args)),
+        "offset_of" => Some(check_core_offset_of(checker, args)),
+        _ => None,
+    }
+}
+
+/// The condition must be a bool and the optional message a string
+fn check_core_assert(checker: &mut TypeChecker, args: &[Expression]) -> Result<AstType> {
+    if args.is_empty() || args.len() > 2 {
+        return Err(CompileError::TypeError(
+            format!(
+                "core.assert() expects a condition and an optional message, got {} argument(s)",
+                args.len()
+            ),
+            checker.get_current_span(),
+        ));
+    }
+
+    let condition = checker.infer_expression_type(&args[0])?;
+    if condition != AstType::Bool {
+        return Err(CompileError::TypeError(
+            format!("core.assert() condition must be bool, got {}", condition),
+            checker.get_current_span(),
+        ));
+    }
+
+    if let Some(message) = args.get(1) {
+        let message_type = checker.infer_expression_type(message)?;
+        let is_string = is_string_type(&message_type)
+            || matches!(&message_type, AstType::Generic { name, .. } if StdlibTypeRegistry::is_string_type(name));
+        if !is_string {
+            return Err(CompileError::TypeError(
+                format!("core.assert() message must be a string, got {}", message_type),
+                checker.get_current_span(),
+            ));
+        }
+    }
+    Ok(AstType::Void)
+}
+
+/// The first argument must name a struct and the second one of its fields
+fn check_core_offset_of(checker: &TypeChecker, args: &[Expression]) -> Result<AstType> {
+    let span = checker.get_current_span();
+    let (struct_name, field_name) = compiler_intrinsics::offset_of_operands(args)
+        .map_err(|msg| CompileError::TypeError(msg, span.clone()))?;
+    let info = checker.structs.get(struct_name).ok_or_else(|| {
+        CompileError::TypeError(format!("core.offset_of(): unknown struct '{}'", struct_name), span.clone())
+    })?;
+    if !info.fields.iter().any(|(name, _)| name == field_name) {
+        return Err(CompileError::TypeError(
+            format!("core.offset_of(): struct '{}' has no field '{}'", struct_name, field_name),
+            span,
+        ));
+    }
+    Ok(AstType::Usize)
+}
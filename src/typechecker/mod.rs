@@ -37,6 +37,10 @@ pub struct TypeChecker {
     current_span: Option<Span>,
     /// Expected return type for the current function being checked
     current_function_return_type: Option<AstType>,
+    /// Name of the function whose body is being checked
+    current_function: Option<String>,
+    /// Types of locals seen while checking bodies: (function, variable, type)
+    variable_types: Vec<(String, String, AstType)>,
     pub well_known: WellKnownTypes,
     // Cache of loaded stdlib modules for type lookup
     stdlib_modules: HashMap<String, Program>,
@@ -141,6 +145,8 @@ impl TypeChecker {
             current_impl_type: None,
             current_span: None,
             current_function_return_type: None,
+            current_function: None,
+            variable_types: Vec::new(),
             well_known: WellKnownTypes::new(),
             stdlib_modules: HashMap::new(),
             stdlib_methods: HashMap::new(),
@@ -278,6 +284,11 @@ impl TypeChecker {
             ctx.register_behavior_impl(type_name, behavior_name);
         }
 
+        // Register local variable types recorded while checking function bodies
+        for (function, name, var_type) in &self.variable_types {
+            ctx.register_variable(function, name, var_type.clone());
+        }
+
         ctx
     }
 
@@ -324,6 +335,7 @@ impl TypeChecker {
             Expression::BinaryOp { left, op, right } => {
                 inference::infer_binary_op_type(self, left, op, right)
             }
+            Expression::UnaryOp { op, operand } => inference::infer_unary_op_type(self, op, operand),
             Expression::FunctionCall { name, type_args, args } => {
                 inference::infer_function_call_type(self, name, type_args, args)
             }
@@ -460,7 +472,11 @@ impl TypeChecker {
             Expression::ArrayLiteral(elements) => {
                 // Infer type from first element - array literals produce slices
                 if elements.is_empty() {
-                    Ok(AstType::Slice(Box::new(AstType::Void)))
+                    // No element type to infer and no runtime array to hold it
+                    Err(CompileError::TypeError(
+                        "An empty '[]' has no element type or runtime representation; create the collection with `Vec<T>.new(allocator)`".to_string(),
+                        self.get_current_span(),
+                    ))
                 } else {
                     let elem_type = self.infer_expression_type(&elements[0])?;
                     Ok(AstType::Slice(Box::new(elem_type)))
@@ -473,6 +489,8 @@ impl TypeChecker {
 
                 // Infer the type of the scrutinee to properly type pattern bindings
                 let scrutinee_type = self.infer_expression_type(scrutinee)?;
+                validation::check_match_coverage(&self.unwrap_primitive_generic(&scrutinee_type), arms)
+                    .map_err(|msg| CompileError::TypeError(msg, self.get_current_span()))?;
 
                 if arms.is_empty() {
                     Ok(AstType::Void)
@@ -495,19 +513,18 @@ impl TypeChecker {
                         // If the arm body is a block, we need to check if it actually
                         // produces a value or just has side effects before returning
                         let arm_type = if let Expression::Block(stmts) = &arm.body {
-                            // Check if the block has any non-return statements before the return
                             let mut block_type = AstType::Void;
-                            let has_early_return = false;
 
                             for (j, stmt) in stmts.iter().enumerate() {
                                 match stmt {
                                     Statement::Return { .. } => {
-                                        // Don't use return statement to determine block type
+                                        // The arm leaves the match here, so it contributes Void
+                                        // and is excluded from the match's result type
                                         break;
                                     }
                                     Statement::Expression { expr, .. } => {
-                                        // If this is the last statement and there's no early return after it
-                                        if j == stmts.len() - 1 && !has_early_return {
+                                        // The last expression is the value of the arm
+                                        if j == stmts.len() - 1 {
                                             block_type = self.infer_expression_type(expr)?;
                                         } else {
                                             // Still type-check intermediate expressions
@@ -611,8 +628,8 @@ impl TypeChecker {
                 object,
                 method,
                 type_args,
-                args: _,
-            } => inference::infer_method_call_type(self, object, method, type_args),
+                args,
+            } => inference::infer_method_call_type(self, object, method, type_args, args),
             Expression::Loop { body: _ } => {
                 // Loop expressions return void for now
                 Ok(AstType::Void)
@@ -683,6 +700,8 @@ impl TypeChecker {
             }
             Expression::CreateMutableReference(expr) => {
                 // expr.mut_ref() -> MutPtr<T> (if expr is of type T)
+                // A reference to an immutable binding is still accepted here;
+                // lints::mut_ref_of_immutable warns about it until it becomes an error
                 let expr_type = self.infer_expression_type(expr)?;
                 Ok(AstType::mut_ptr(expr_type))
             }
@@ -729,6 +748,10 @@ impl TypeChecker {
                     type_args: vec![AstType::Void],
                 })
             }
+            Expression::Null => {
+                // Untyped null pointer - the binding or field it is stored into fixes the pointee
+                Ok(AstType::raw_ptr(AstType::Void))
+            }
             Expression::CollectionLoop { .. } => {
                 // collection.loop() returns unit/void
                 Ok(AstType::Void)
@@ -807,6 +830,20 @@ impl TypeChecker {
                         self.stdlib_functions.insert(key, sig);
                     }
                 }
+                Declaration::ExternalFunction(ext) => {
+                    // C functions a module declares, e.g. libm's atan2 in @std.math
+                    let key = format!("{}::{}", module_path, ext.name);
+                    let sig = FunctionSignature {
+                        params: ext.args
+                            .iter()
+                            .enumerate()
+                            .map(|(i, t)| (format!("arg{}", i), t.clone()))
+                            .collect(),
+                        return_type: ext.return_type.clone(),
+                        is_external: true,
+                    };
+                    self.stdlib_functions.insert(key, sig);
+                }
                 Declaration::Enum(def) => {
                     let variants: Vec<(String, Option<AstType>)> = def.variants
                         .iter()
@@ -999,10 +1036,12 @@ impl TypeChecker {
     ) -> Result<()> {
         use crate::ast::Pattern;
 
+        // Narrow at every level so nested payloads (e.g. `Some(Ok(x))`) see concrete types
+        let scrutinee_type = &self.unwrap_primitive_generic(scrutinee_type);
+
         match pattern {
             Pattern::Identifier(name) => {
-                let binding_type = self.unwrap_primitive_generic(scrutinee_type);
-                self.declare_variable(name, binding_type, false)?;
+                self.declare_variable(name, scrutinee_type.clone(), false)?;
             }
             Pattern::EnumLiteral { variant, payload } => {
                 if let Some(payload_pattern) = payload {
@@ -1435,4 +1474,277 @@ mod tests {
         ";
         assert!(check_program(input).is_ok());
     }
+
+    // ========================================================================
+    // Reference Mutability Tests
+    // ========================================================================
+
+    #[test]
+    fn test_mut_ref_of_immutable_binding_still_compiles() {
+        // Warning phase: the lint reports this, the type checker accepts it
+        let input = "
+            main = () void {
+                x = 10
+                p = x.mut_ref()
+            }
+        ";
+        assert!(check_program(input).is_ok());
+    }
+
+    #[test]
+    fn test_mut_ref_of_mutable_binding() {
+        let input = "
+            main = () void {
+                x ::= 10
+                p = x.mut_ref()
+            }
+        ";
+        assert!(check_program(input).is_ok());
+    }
+
+    #[test]
+    fn test_null_requires_pointer_context() {
+        let input = "
+            main = () void {
+                p = null
+            }
+        ";
+        let result = check_program(input);
+        assert!(result.is_err());
+        if let Err(CompileError::TypeError(msg, _)) = result {
+            assert!(msg.contains("pointer type of 'null'"));
+        }
+    }
+
+    #[test]
+    fn test_null_with_pointer_annotation() {
+        let input = "
+            main = () void {
+                p: MutPtr<i32> = null
+            }
+        ";
+        assert!(check_program(input).is_ok());
+    }
+
+    // ========================================================================
+    // Empty Array Literal Tests
+    // ========================================================================
+
+    #[test]
+    fn test_empty_array_without_annotation() {
+        let input = "
+            main = () void {
+                v = []
+            }
+        ";
+        let err = check_program(input).expect_err("an unannotated [] should be rejected");
+        assert!(
+            matches!(&err, CompileError::TypeError(msg, _) if msg.contains("empty '[]'") && msg.contains("Vec<T>.new")),
+            "unexpected error: {:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_empty_array_with_annotation_is_rejected() {
+        // The annotation names an element type, but there is still no runtime array to build
+        let input = "
+            main = () void {
+                v:: Array<i32> = []
+            }
+        ";
+        let err = check_program(input).expect_err("an annotated [] should be rejected");
+        assert!(
+            matches!(&err, CompileError::TypeError(msg, _) if msg.contains("Vec<T>.new")),
+            "unexpected error: {:?}",
+            err
+        );
+    }
+
+    // ========================================================================
+    // core.assert Tests
+    // ========================================================================
+
+    fn core_assert_error(call: &str) -> String {
+        let input = format!("{{ core }} = @std\n\nmain = () void {{\n    {}\n}}\n", call);
+        match check_program(&input).expect_err("the assert call should be rejected") {
+            CompileError::TypeError(msg, _) => msg,
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_core_assert_accepts_bool_and_message() {
+        let input = "{ core } = @std\n\nmain = () void {\n    core.assert(1 < 2, \"ordering\")\n}\n";
+        assert!(check_program(input).is_ok());
+    }
+
+    #[test]
+    fn test_core_assert_rejects_wrong_argument_count() {
+        assert!(core_assert_error("core.assert()").contains("got 0 argument(s)"));
+        assert!(core_assert_error("core.assert(true, \"a\", \"b\")").contains("got 3 argument(s)"));
+    }
+
+    #[test]
+    fn test_core_assert_rejects_non_bool_condition() {
+        assert!(core_assert_error("core.assert(1)").contains("condition must be bool"));
+    }
+
+    #[test]
+    fn test_core_assert_rejects_non_string_message() {
+        assert!(core_assert_error("core.assert(true, 42)").contains("message must be a string"));
+    }
+
+    #[test]
+    fn test_core_offset_of_checks_struct_and_field() {
+        let input = "{ core } = @std\n\nPoint: {\n    x: i32,\n    y: i32\n}\n\nmain = () void {\n    a = core.offset_of(Point, y)\n    b = core.offset_of(Point, z)\n}\n";
+        let err = check_program(input).expect_err("an unknown field should be rejected");
+        assert!(
+            matches!(&err, CompileError::TypeError(msg, _) if msg.contains("has no field 'z'")),
+            "unexpected error: {:?}",
+            err
+        );
+    }
+
+    // ========================================================================
+    // Pattern Binding Tests
+    // ========================================================================
+
+    #[test]
+    fn test_nested_enum_pattern_binds_innermost_type() {
+        let input = "pick = (o: Option<Result<i32, StaticString>>) i32 {
+            return o ?
+                | Some(Ok(x)) {
+                    n: i32 = x
+                    n
+                }
+                | Some(Err(e)) {
+                    msg: StaticString = e
+                    0
+                }
+                | None { 0 }
+        }";
+        assert!(check_program(input).is_ok());
+
+        let mismatch = "pick = (o: Option<Result<i32, StaticString>>) i32 {
+            return o ?
+                | Some(Ok(x)) {
+                    msg: StaticString = x
+                    0
+                }
+                | _ { 0 }
+        }";
+        assert!(check_program(mismatch).is_err());
+    }
+
+    // ========================================================================
+    // Stdlib Module Function Tests
+    // ========================================================================
+
+    /// Type of local `var` in `func` after checking `input` with `module` loaded
+    fn local_type_with_module(input: &str, module: &str, func: &str, var: &str) -> AstType {
+        let mut module_system = crate::module_system::ModuleSystem::new();
+        module_system.load_module(module).expect("failed to load stdlib module");
+
+        let program = Parser::new(Lexer::new(input)).parse_program().unwrap();
+        let mut type_checker = TypeChecker::new();
+        type_checker.with_stdlib_modules(module_system.get_modules());
+        type_checker.check_program(&program).unwrap();
+
+        type_checker
+            .variable_types
+            .iter()
+            .find(|(f, v, _)| f == func && v == var)
+            .map(|(_, _, t)| t.clone())
+            .unwrap_or_else(|| panic!("no local '{}' in '{}'", var, func))
+    }
+
+    #[test]
+    fn test_math_two_argument_functions_return_f64() {
+        for call in ["math.atan2(1.0, 2.0)", "math.hypot(3.0, 4.0)", "math.fmod(7.5, 2.0)"] {
+            let input = format!("{{ math }} = @std\n\nmain = () void {{\n    x = {}\n}}\n", call);
+            assert_eq!(local_type_with_module(&input, "@std.math", "main", "x"), AstType::F64, "{}", call);
+        }
+    }
+
+    #[test]
+    fn test_math_abs_resolves_by_argument_type() {
+        // An untyped integer literal picks the integer overload
+        let input = "{ math } = @std\n\nmain = () void {\n    x = math.abs(-5)\n}\n";
+        assert_eq!(local_type_with_module(input, "@std.math", "main", "x"), AstType::I64);
+
+        let input = "{ math } = @std\n\nmain = () void {\n    x = math.abs(-5.0)\n}\n";
+        assert_eq!(local_type_with_module(input, "@std.math", "main", "x"), AstType::F64);
+    }
+
+    #[test]
+    fn test_math_inverse_trig_and_logs_return_f64() {
+        for func in ["asin", "acos", "atan", "log10", "log2"] {
+            let input = format!("{{ math }} = @std\n\nmain = () void {{\n    x = math.{}(0.5)\n}}\n", func);
+            assert_eq!(local_type_with_module(&input, "@std.math", "main", "x"), AstType::F64, "math.{}", func);
+        }
+    }
+
+    // ========================================================================
+    // Selective Import Tests
+    // ========================================================================
+
+    #[test]
+    fn test_selective_import_rejects_sibling_module() {
+        let input = "
+            { io } = @std
+
+            main = () f64 {
+                return math.sqrt(4.0)
+            }
+        ";
+        match check_program(input) {
+            Err(CompileError::ImportError(msg, _)) => {
+                assert!(msg.contains("'math' is not imported"), "unexpected message: {}", msg)
+            }
+            other => panic!("expected an import error, got {:?}", other.err()),
+        }
+    }
+
+    #[test]
+    fn test_selective_import_allows_listed_module() {
+        let input = "
+            { io, math } = @std
+
+            main = () void {
+                x = math.pi
+            }
+        ";
+        assert!(check_program(input).is_ok());
+    }
+
+    // ========================================================================
+    // Increment / Decrement Tests
+    // ========================================================================
+
+    #[test]
+    fn test_increment_immutable_binding() {
+        let input = "
+            main = () void {
+                x = 10
+                x++
+            }
+        ";
+        let result = check_program(input);
+        assert!(result.is_err());
+        if let Err(CompileError::TypeError(msg, _)) = result {
+            assert!(msg.contains("immutable variable 'x'"));
+        }
+    }
+
+    #[test]
+    fn test_increment_in_expression_position() {
+        let input = "
+            main = () void {
+                x ::= 10
+                y = x++
+            }
+        ";
+        assert!(matches!(check_program(input), Err(CompileError::SyntaxError(..))));
+    }
 }
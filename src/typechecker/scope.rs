@@ -38,6 +38,8 @@ pub fn declare_variable(
         is_initialized: false,
     };
 
+    record_variable_type(checker, name, &info.type_);
+
     if let Some(scope) = checker.scopes.last_mut() {
         scope.insert(name.to_string(), info);
     } else {
@@ -73,6 +75,8 @@ pub fn declare_variable_with_init(
         is_initialized,
     };
 
+    record_variable_type(checker, name, &info.type_);
+
     if let Some(scope) = checker.scopes.last_mut() {
         scope.insert(name.to_string(), info);
     } else {
@@ -85,6 +89,15 @@ pub fn declare_variable_with_init(
     Ok(())
 }
 
+/// Remember the type of a local declared inside a function body
+fn record_variable_type(checker: &mut TypeChecker, name: &str, type_: &AstType) {
+    if let Some(function) = &checker.current_function {
+        checker
+            .variable_types
+            .push((function.clone(), name.to_string(), type_.clone()));
+    }
+}
+
 /// Mark a variable as initialized
 pub fn mark_variable_initialized(checker: &mut TypeChecker, name: &str) -> Result<()> {
     let span = checker.get_current_span();
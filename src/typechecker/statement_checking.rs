@@ -1,6 +1,6 @@
 //! Statement type checking
 
-use crate::ast::{AstType, LoopKind, Statement};
+use crate::ast::{AstType, Expression, LoopKind, Statement};
 use crate::error::{CompileError, Result};
 use crate::typechecker::TypeChecker;
 use super::validation::types_compatible;
@@ -86,6 +86,15 @@ pub fn check_statement(checker: &mut TypeChecker, statement: &Statement) -> Resu
                         span.clone(),
                     )?;
                 } else {
+                    if matches!(init_expr, Expression::Null) {
+                        return Err(CompileError::TypeError(
+                            format!(
+                                "Cannot infer the pointer type of 'null' for '{}'; annotate it (e.g. `{}: MutPtr<T> = null`)",
+                                name, name
+                            ),
+                            span.clone(),
+                        ));
+                    }
                     // Inferred type from initializer
                     checker.declare_variable_with_init_and_span(
                         name,
@@ -306,3 +306,66 @@ fn contains_import_expression(expr: &crate::ast::Expression) -> bool {
     }
 }
 
+
+/// Check that a match over a scalar scrutinee covers every value.
+/// Bools need both values; numbers and strings always need a default arm.
+/// Only unguarded arms count towards coverage; enum scrutinees are not checked here.
+pub fn check_match_coverage(
+    scrutinee_type: &AstType,
+    arms: &[crate::ast::MatchArm],
+) -> Result<(), String> {
+    use crate::ast::{Expression, Pattern};
+
+    fn covers(pattern: &Pattern, catch_all: &mut bool, bools: &mut [bool; 2]) {
+        match pattern {
+            Pattern::Wildcard | Pattern::Identifier(_) => *catch_all = true,
+            Pattern::Binding { pattern, .. } => covers(pattern, catch_all, bools),
+            Pattern::Literal(Expression::Boolean(b)) => bools[*b as usize] = true,
+            Pattern::Or(patterns) => {
+                for p in patterns {
+                    covers(p, catch_all, bools);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut catch_all = false;
+    let mut bools = [false; 2];
+    for arm in arms.iter().filter(|arm| arm.guard.is_none()) {
+        covers(&arm.pattern, &mut catch_all, &mut bools);
+    }
+    if catch_all {
+        return Ok(());
+    }
+
+    if matches!(scrutinee_type, AstType::Bool) {
+        let missing: Vec<&str> = [(bools[1], "true"), (bools[0], "false")]
+            .into_iter()
+            .filter(|(covered, _)| !covered)
+            .map(|(_, name)| name)
+            .collect();
+        if !missing.is_empty() {
+            return Err(format!(
+                "Non-exhaustive match on bool: missing `{}` arm (add it or a `_` arm)",
+                missing.join("` and `")
+            ));
+        }
+    }
+
+    // Numbers and strings are open domains: literal arms can never cover every value
+    let open_domain = if scrutinee_type.is_numeric() {
+        Some(scrutinee_type.to_string())
+    } else if super::inference::helpers::is_string_type(scrutinee_type) {
+        Some("string".to_string())
+    } else {
+        None
+    };
+    if let Some(domain) = open_domain {
+        return Err(format!(
+            "Non-exhaustive match on {}: literal arms cannot cover every value, add a default arm (`_` or a binding)",
+            domain
+        ));
+    }
+    Ok(())
+}
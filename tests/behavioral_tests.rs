@@ -264,6 +264,34 @@ fn test_negative_numbers() {
     assert_eq!(result.exit_code, 0, "Negative number handling failed");
 }
 
+/// Test unary negation (integer and float) and bitwise complement
+#[test]
+fn test_unary_negate_and_complement() {
+    let source = r#"
+        main = () i32 {
+            x = 5
+            y = -x
+            y != -5 ? | true { return 1 } | false { }
+            z = -(x + 2)
+            z != -7 ? | true { return 2 } | false { }
+
+            ~0 != -1 ? | true { return 3 } | false { }
+            (~5 & 7) != 2 ? | true { return 4 } | false { }
+            ~y != 4 ? | true { return 5 } | false { }
+
+            f = -3.5
+            f > -3.4 ? | true { return 6 } | false { }
+            f < -3.6 ? | true { return 7 } | false { }
+            g = -f
+            g < 3.4 ? | true { return 8 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Unary operator check {} failed", result.exit_code);
+}
+
 // ============================================================================
 // CONTROL FLOW TESTS
 // ============================================================================
@@ -298,6 +326,162 @@ fn test_conditional_false_branch() {
     assert_eq!(result.exit_code, 0, "Conditional false branch failed");
 }
 
+// ============================================================================
+// PATTERN MATCHING TESTS
+// ============================================================================
+
+/// Test integer literal arms with an identifier catch-all that binds the value
+#[test]
+fn test_match_int_literals_with_binding() {
+    let source = r#"
+        describe = (x: i32) StaticString {
+            return x ?
+                | 0 { "zero" }
+                | 1 { "one" }
+                | n { "many" }
+        }
+
+        plus_ten = (x: i64) i64 {
+            return x ?
+                | 0 { -1 }
+                | n { n + 10 }
+        }
+
+        main = () i32 {
+            describe(0) != "zero" ? | true { return 1 } | false { }
+            describe(1) != "one" ? | true { return 2 } | false { }
+            describe(7) != "many" ? | true { return 3 } | false { }
+
+            // The catch-all sees the scrutinee's value, at the scrutinee's width
+            plus_ten(0) != -1 ? | true { return 4 } | false { }
+            plus_ten(5) != 15 ? | true { return 5 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Integer match check {} failed", result.exit_code);
+}
+
+/// Test matching a bool against literal arms, and rejecting a missing arm
+#[test]
+fn test_match_bool_literals() {
+    let source = r#"
+        to_int = (b: bool) i32 {
+            return b ?
+                | true { 1 }
+                | false { 0 }
+        }
+
+        main = () i32 {
+            to_int(true) != 1 ? | true { return 1 } | false { }
+            to_int(false) != 0 ? | true { return 2 } | false { }
+
+            // A wildcard covers the other value
+            flag = 3 > 4
+            picked = flag ?
+                | true { 10 }
+                | _ { 20 }
+            picked != 20 ? | true { return 3 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Bool match check {} failed", result.exit_code);
+
+    let missing_arm = r#"
+        main = () i32 {
+            flag = true
+            return flag ?
+                | true { 1 }
+        }
+    "#;
+    let err = compile_and_run(missing_arm).expect_err("bool match without a false arm should not compile");
+    assert!(err.contains("missing `false` arm"), "unexpected error: {}", err);
+}
+
+#[test]
+fn test_match_string_literals() {
+    let source = r#"
+        answer = (s: StaticString) i32 {
+            return s ?
+                | "yes" { 1 }
+                | "no" { 2 }
+                | _ { 3 }
+        }
+
+        main = () i32 {
+            answer("yes") != 1 ? | true { return 1 } | false { }
+            answer("no") != 2 ? | true { return 2 } | false { }
+            answer("maybe") != 3 ? | true { return 3 } | false { }
+            // Prefixes must not match
+            answer("y") != 3 ? | true { return 4 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "String match check {} failed", result.exit_code);
+
+    let no_wildcard = r#"
+        main = () i32 {
+            s = "yes"
+            return s ?
+                | "yes" { 1 }
+                | "no" { 0 }
+        }
+    "#;
+    let err = compile_and_run(no_wildcard).expect_err("string match without a `_` arm should not compile");
+    assert!(err.contains("add a default arm"), "unexpected error: {}", err);
+}
+
+#[test]
+fn test_match_int_requires_default_arm() {
+    let source = r#"
+        main = () i32 {
+            n = 2
+            return n ?
+                | 1 { 10 }
+                | 2 { 20 }
+        }
+    "#;
+    let err = compile_and_run(source).expect_err("int match without a default arm should not compile");
+    assert!(err.contains("Non-exhaustive match on i32"), "unexpected error: {}", err);
+    assert!(err.contains("add a default arm"), "unexpected error: {}", err);
+}
+
+#[test]
+fn test_match_return_arm_does_not_decide_type() {
+    let source = r#"
+        scale = (n: i64) i64 {
+            // The first arm leaves the function; the value arm types the match
+            big = n > 0 ?
+                | false { return -1 }
+                | true { n * 1000000000 }
+            return big + 1
+        }
+
+        label = (n: i32) StaticString {
+            s = n ?
+                | 0 { return "zero" }
+                | _ { "many" }
+            return s
+        }
+
+        main = () i32 {
+            scale(5) != 5000000001 ? | true { return 1 } | false { }
+            scale(-3) != -1 ? | true { return 2 } | false { }
+            label(0) != "zero" ? | true { return 3 } | false { }
+            label(7) != "many" ? | true { return 4 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Return-arm match check {} failed", result.exit_code);
+}
+
 // ============================================================================
 // FUNCTION TESTS
 // ============================================================================
@@ -344,6 +528,27 @@ fn test_nested_function_calls() {
     assert_eq!(result.exit_code, 0, "Nested function calls failed");
 }
 
+#[test]
+fn test_many_functions_compile_correctly() {
+    // Stress the per-function codegen path: each step_i adds i, and main chains them all
+    const COUNT: i64 = 300;
+    let mut source = String::new();
+    for i in 0..COUNT {
+        source.push_str(&format!("step_{i} = (x: i64) i64 {{\n    return x + {i}\n}}\n\n"));
+    }
+    source.push_str("main = () i32 {\n    total:: i64 = 0\n");
+    for i in 0..COUNT {
+        source.push_str(&format!("    total = step_{i}(total)\n"));
+    }
+    let expected = COUNT * (COUNT - 1) / 2;
+    source.push_str(&format!(
+        "    total != {expected} ? | true {{ return 1 }} | false {{ }}\n    return 0\n}}\n"
+    ));
+
+    let result = run_expecting_success(&source);
+    assert_eq!(result.exit_code, 0, "Chained calls across {} functions gave the wrong total", COUNT);
+}
+
 // ============================================================================
 // STRUCT TESTS
 // ============================================================================
@@ -395,8 +600,6 @@ fn test_nested_struct_field_access() {
 }
 
 /// Test for struct field assignment type mismatch
-/// Note: Direct struct field assignment (p.x = 10) is currently not supported
-/// This test uses mutable variable reassignment instead
 #[test]
 fn test_struct_mutable_reassignment() {
     let source = r#"
@@ -424,240 +627,1780 @@ fn test_struct_mutable_reassignment() {
     assert_eq!(result.exit_code, 0, "Struct mutable reassignment failed");
 }
 
-// ============================================================================
-// OUTPUT VERIFICATION TESTS
-// ============================================================================
-
+/// Test assigning a struct copies it: mutating the copy leaves the original alone
 #[test]
-fn test_println_output() {
+fn test_struct_copy_is_independent() {
     let source = r#"
-        { io } = @std.io
+        Point: {
+            x: i32,
+            y: i32
+        }
+
+        bump = (p: Point) Point {
+            q ::= p
+            q.x = q.x + 1
+            return q
+        }
 
         main = () i32 {
-            io.println("hello")
+            original ::= Point { x: 1, y: 2 }
+            copy ::= original
+            copy.x = 10
+            copy.y = 20
+            original.x != 1 || original.y != 2 ? | true { return 1 } | false { }
+            copy.x != 10 || copy.y != 20 ? | true { return 2 } | false { }
+
+            // Writes to the original don't leak into the copy either
+            original.y = 5
+            copy.y != 20 ? | true { return 3 } | false { }
+
+            // Passing by value copies too
+            bumped = bump(original)
+            bumped.x != 2 ? | true { return 4 } | false { }
+            original.x != 1 ? | true { return 5 } | false { }
             return 0
         }
     "#;
 
     let result = run_expecting_success(source);
-    assert_eq!(result.exit_code, 0);
-    assert!(
-        result.stdout.contains("hello"),
-        "Expected 'hello' in output, got: {}",
-        result.stdout
-    );
+    assert_eq!(result.exit_code, 0, "Struct copy check {} failed", result.exit_code);
 }
 
+/// Test functions can return structs larger than the return registers
 #[test]
-fn test_string_interpolation_output() {
+fn test_large_struct_return() {
     let source = r#"
-        { io } = @std.io
+        Big: {
+            a: i64,
+            b: i64,
+            c: i64,
+            d: i64,
+            e: i64,
+            f: i64,
+            g: i64,
+            h: i64
+        }
+
+        make_big = (base: i64) Big {
+            return Big { a: base, b: base + 1, c: base + 2, d: base + 3, e: base + 4, f: base + 5, g: base + 6, h: base + 7 }
+        }
+
+        shifted = (base: i64) Big {
+            big = make_big(base * 10)
+            return big
+        }
 
         main = () i32 {
-            x = 42
-            io.println("value: ${x}")
+            big = shifted(3)
+            big.a != 30 ? | true { return 1 } | false { }
+            big.d != 33 ? | true { return 2 } | false { }
+            big.h != 37 ? | true { return 3 } | false { }
             return 0
         }
     "#;
 
     let result = run_expecting_success(source);
-    assert_eq!(result.exit_code, 0);
-    assert!(
-        result.stdout.contains("value: 42"),
-        "Expected 'value: 42' in output, got: {}",
-        result.stdout
-    );
+    assert_eq!(result.exit_code, 0, "Large struct return check {} failed", result.exit_code);
 }
 
-// ============================================================================
-// VARIABLE DECLARATION TESTS - All 6 documented forms
-// ============================================================================
-
-/// Test immutable variable with type inference: x = 10
+/// Test packed struct layout has no padding between fields
 #[test]
-fn test_variable_immutable_inferred() {
+fn test_packed_struct_size() {
     let source = r#"
-        main = () i32 {
-            x = 42
-            x == 42 ?
-                | true { return 0 }
-                | false { return 1 }
+        { compiler } = @std
+
+        Header: packed {
+            tag: u8,
+            length: u32
         }
-    "#;
 
-    let result = run_expecting_success(source);
-    assert_eq!(result.exit_code, 0, "Immutable inferred variable failed");
-}
+        Padded: {
+            tag: u8,
+            length: u32
+        }
 
-/// Test immutable variable with explicit type: x: i32 = 10
-#[test]
-fn test_variable_immutable_explicit_type() {
-    let source = r#"
         main = () i32 {
-            x: i64 = 100
-            x == 100 ?
-                | true { return 0 }
-                | false { return 1 }
+            compiler.sizeof<Header>() != 5 ? | true { return 1 } | false { }
+            compiler.sizeof<Padded>() != 8 ? | true { return 2 } | false { }
+
+            h = Header { tag: 7, length: 1000 }
+            h.tag != 7 ? | true { return 3 } | false { }
+            h.length != 1000 ? | true { return 4 } | false { }
+            return 0
         }
     "#;
 
     let result = run_expecting_success(source);
-    assert_eq!(result.exit_code, 0, "Immutable explicit type variable failed");
+    assert_eq!(result.exit_code, 0, "Packed struct check {} failed", result.exit_code);
 }
 
-/// Test mutable variable with inference: x ::= 10
+/// Test offset_of reports field offsets including alignment padding
 #[test]
-fn test_variable_mutable_inferred() {
+fn test_struct_offset_of() {
     let source = r#"
-        main = () i32 {
-            x ::= 5
-            x = x + 10
-            x == 15 ?
-                | true { return 0 }
-                | false { return 1 }
+        { core } = @std
+
+        Record: {
+            kind: u8,
+            length: u32,
+            payload: u64
         }
-    "#;
 
-    let result = run_expecting_success(source);
-    assert_eq!(result.exit_code, 0, "Mutable inferred variable failed");
-}
+        Header: packed {
+            tag: u8,
+            length: u32
+        }
 
-/// Test mutable variable with explicit type: x:: i32 = 10
-#[test]
-fn test_variable_mutable_explicit() {
-    let source = r#"
         main = () i32 {
-            x:: i32 = 20
-            x = x * 2
-            x == 40 ?
-                | true { return 0 }
-                | false { return 1 }
+            // u8 then padding up to u32 alignment
+            core.offset_of(Record, kind) != 0 ? | true { return 1 } | false { }
+            core.offset_of(Record, length) != 4 ? | true { return 2 } | false { }
+            core.offset_of(Record, "payload") != 8 ? | true { return 3 } | false { }
+
+            // Packed structs have no padding
+            core.offset_of(Header, length) != 1 ? | true { return 4 } | false { }
+            return 0
         }
     "#;
 
     let result = run_expecting_success(source);
-    assert_eq!(result.exit_code, 0, "Mutable explicit type variable failed");
+    assert_eq!(result.exit_code, 0, "offset_of check {} failed", result.exit_code);
 }
 
-// ============================================================================
-// UFC (UNIFORM FUNCTION CALL) TESTS
-// ============================================================================
-
-/// Test basic UFC - function called as method
+/// Test union fields share storage so reads reinterpret the written bytes
 #[test]
-fn test_ufc_basic() {
+fn test_union_reinterprets_bits() {
     let source = r#"
-        double = (n: i32) i32 {
-            return n * 2
+        { compiler } = @std
+
+        Bits: union {
+            f: f32,
+            u: u32
         }
 
         main = () i32 {
-            // Traditional call
-            a = double(5)
-            // UFC call
-            b = 5.double()
+            compiler.sizeof<Bits>() != 4 ? | true { return 1 } | false { }
 
-            (a == 10) ?
-                | true {
-                    (b == 10) ?
-                        | true { return 0 }
-                        | false { return 2 }
-                }
-                | false { return 1 }
+            b = Bits { f: 1.0 }
+            // IEEE-754 single precision 1.0 is 0x3F800000
+            b.u != 1065353216 ? | true { return 2 } | false { }
+
+            c = Bits { u: 1073741824 }
+            c.f != 2.0 ? | true { return 3 } | false { }
+            return 0
         }
     "#;
 
     let result = run_expecting_success(source);
-    assert_eq!(result.exit_code, 0, "UFC basic call failed");
+    assert_eq!(result.exit_code, 0, "Union reinterpretation check {} failed", result.exit_code);
 }
 
-/// Test UFC chaining - multiple UFC calls in sequence
+/// Test a union sized by a struct member larger than 8 bytes holds every field
 #[test]
-fn test_ufc_chaining() {
+fn test_union_with_large_struct_member() {
     let source = r#"
-        double = (n: i32) i32 { return n * 2 }
-        add_one = (n: i32) i32 { return n + 1 }
+        { compiler } = @std
+
+        Triple: {
+            a: i64,
+            b: i64,
+            c: i64
+        }
+
+        Wide: union {
+            small: i32,
+            triple: Triple
+        }
 
         main = () i32 {
-            // Chain: 5.double() = 10, 10.add_one() = 11, 11.double() = 22
-            result = 5.double().add_one().double()
-            result == 22 ?
-                | true { return 0 }
-                | false { return 1 }
+            compiler.sizeof<Wide>() != 24 ? | true { return 1 } | false { }
+
+            w = Wide { triple: Triple { a: 11, b: 22, c: 33 } }
+            t = w.triple
+            t.a != 11 ? | true { return 2 } | false { }
+            t.b != 22 ? | true { return 3 } | false { }
+            t.c != 33 ? | true { return 4 } | false { }
+
+            s = Wide { small: 7 }
+            s.small != 7 ? | true { return 5 } | false { }
+            return 0
         }
     "#;
 
     let result = run_expecting_success(source);
-    assert_eq!(result.exit_code, 0, "UFC chaining failed");
+    assert_eq!(result.exit_code, 0, "Large union member check {} failed", result.exit_code);
 }
 
-// ============================================================================
-// FLOAT ARITHMETIC TESTS
-// ============================================================================
-
-/// Test f64 basic arithmetic via function parameters
+/// Test bitfields pack into one byte and read back independently
 #[test]
-fn test_float64_arithmetic() {
+fn test_struct_bitfields() {
     let source = r#"
-        add_floats = (a: f64, b: f64) f64 {
-            return a + b
+        { compiler } = @std
+
+        Flags: {
+            mode: u8 : 3,
+            enabled: u8 : 1,
+            level: u8 : 4
         }
 
         main = () i32 {
-            sum = add_floats(10.5, 2.5)
-
-            // Check sum is approximately 13.0
-            (sum > 12.9) ?
-                | true {
-                    (sum < 13.1) ?
-                        | true { return 0 }
-                        | false { return 2 }
-                }
-                | false { return 1 }
+            compiler.sizeof<Flags>() != 1 ? | true { return 1 } | false { }
+
+            f ::= Flags { mode: 5, enabled: 1, level: 9 }
+            f.mode != 5 ? | true { return 2 } | false { }
+            f.enabled != 1 ? | true { return 3 } | false { }
+            f.level != 9 ? | true { return 4 } | false { }
+
+            // Setting one field leaves its neighbours untouched
+            p = f.mut_ref()
+            p.val.level = 3
+            p.val.mode = 2
+            p.val.enabled = 0
+            f.mode != 2 ? | true { return 5 } | false { }
+            f.enabled != 0 ? | true { return 6 } | false { }
+            f.level != 3 ? | true { return 7 } | false { }
+            return 0
         }
     "#;
 
     let result = run_expecting_success(source);
-    assert_eq!(result.exit_code, 0, "Float64 arithmetic failed");
+    assert_eq!(result.exit_code, 0, "Bitfield check {} failed", result.exit_code);
 }
 
 // ============================================================================
-// COMPARISON OPERATOR TESTS
+// POINTER TESTS
 // ============================================================================
 
-/// Test less than operator
+/// Test taking a reference and reading/writing through it
 #[test]
-fn test_comparison_less_than() {
+fn test_pointer_address_and_dereference() {
     let source = r#"
         main = () i32 {
-            (5 < 10) ?
-                | true { return 0 }
-                | false { return 1 }
+            x ::= 42
+            r = x.ref()
+            r.val != 42 ? | true { return 1 } | false { }
+
+            p = x.mut_ref()
+            p.val = 7
+            x != 7 ? | true { return 2 } | false { }
+            p.val != 7 ? | true { return 3 } | false { }
+            return 0
         }
     "#;
 
     let result = run_expecting_success(source);
-    assert_eq!(result.exit_code, 0, "Less than comparison failed");
+    assert_eq!(result.exit_code, 0, "Pointer dereference check {} failed", result.exit_code);
 }
 
-/// Test greater than operator
+/// Test ptr.offset(n): raw pointers step in bytes, typed pointers in elements
 #[test]
-fn test_comparison_greater_than() {
+fn test_pointer_offset() {
     let source = r#"
+        { compiler } = @std
+
         main = () i32 {
-            (10 > 5) ?
-                | true { return 0 }
-                | false { return 1 }
+            buf = compiler.raw_allocate(16)
+            compiler.store<i64>(buf, 10)
+            compiler.store<i64>(buf.offset(8), 20)
+            compiler.load<i64>(buf) != 10 ? | true { return 1 } | false { }
+            compiler.load<i64>(buf.offset(8)) != 20 ? | true { return 2 } | false { }
+            compiler.raw_deallocate(buf, 16)
+
+            x ::= 5
+            p = x.mut_ref()
+            same = p.offset(0)
+            same.val != 5 ? | true { return 3 } | false { }
+
+            // A typed i64 pointer steps 8 bytes per element
+            items = compiler.raw_allocate(24)
+            compiler.store<i64>(items, 100)
+            compiler.store<i64>(items.offset(8), 200)
+            compiler.store<i64>(items.offset(16), 300)
+            nums = items as MutPtr<i64>
+            second = nums.offset(1)
+            second.val != 200 ? | true { return 4 } | false { }
+            third = nums.offset(2)
+            third.val != 300 ? | true { return 5 } | false { }
+            third.val = 333
+            compiler.load<i64>(items.offset(16)) != 333 ? | true { return 6 } | false { }
+            compiler.raw_deallocate(items, 24)
+            return 0
         }
     "#;
 
     let result = run_expecting_success(source);
-    assert_eq!(result.exit_code, 0, "Greater than comparison failed");
+    assert_eq!(result.exit_code, 0, "Pointer offset check {} failed", result.exit_code);
 }
 
-/// Test not equal operator
+/// Test dereferencing a null pointer panics cleanly instead of segfaulting (debug builds)
 #[test]
-fn test_comparison_not_equal() {
+fn test_null_pointer_dereference_panics() {
+    let source = r#"
+        { compiler } = @std
+
+        main = () i32 {
+            p = compiler.null_ptr()
+            v = p.val
+            return 0
+        }
+    "#;
+
+    let result = compile_and_run(source).expect("Null dereference should compile");
+    assert_eq!(result.exit_code, -6, "Expected SIGABRT from panic, got {}", result.exit_code);
+    assert!(
+        result.stderr.contains("panic: null pointer dereference"),
+        "Unexpected stderr: {}",
+        result.stderr
+    );
+}
+
+/// Test ptr.is_null()/is_not_null() on raw and mutable pointers
+#[test]
+fn test_pointer_null_checks() {
+    let source = r#"
+        { compiler } = @std
+
+        main = () i32 {
+            compiler.null_ptr().is_null() ? | false { return 1 } | true { }
+
+            buf = compiler.raw_allocate(8)
+            buf.is_null() ? | true { return 2 } | false { }
+            buf.is_not_null() ? | false { return 3 } | true { }
+            compiler.raw_deallocate(buf, 8)
+
+            x ::= 1
+            p = x.mut_ref()
+            p.is_null() ? | true { return 4 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Pointer null check {} failed", result.exit_code);
+}
+
+/// Test a null pointer field takes the field's pointer type
+#[test]
+fn test_null_pointer_field() {
+    let source = r#"
+        Node: {
+            value: i32,
+            next: MutPtr<Node>
+        }
+
+        main = () i32 {
+            n = Node { value: 7, next: null }
+            n.next.is_null() ? | false { return 1 } | true { }
+            n.value != 7 ? | true { return 2 } | false { }
+
+            head:: MutPtr<Node> = null
+            head.is_null() ? | false { return 3 } | true { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Null pointer field check {} failed", result.exit_code);
+}
+
+/// Test a self-referential struct through allocator-owned nodes: build, traverse, free
+#[test]
+fn test_linked_list_traversal() {
+    let source = r#"
+        { compiler } = @std
+        { default_gpa } = @std.memory.gpa
+
+        Node: {
+            value: i32,
+            next: MutPtr<Node>
+        }
+
+        main = () i32 {
+            alloc = default_gpa()
+            size = compiler.sizeof<Node>()
+
+            third = alloc.allocate(size)
+            compiler.store<Node>(third, Node { value: 30, next: null })
+            second = alloc.allocate(size)
+            compiler.store<Node>(second, Node { value: 20, next: third })
+            first = alloc.allocate(size)
+            compiler.store<Node>(first, Node { value: 10, next: second })
+
+            sum ::= 0
+            count ::= 0
+            cur:: MutPtr<Node> = first
+            loop(() {
+                cur.is_null() ? | true { break } | false { }
+                node = compiler.load<Node>(cur)
+                sum = sum + node.value
+                count++
+                alloc.deallocate(cur, size)
+                cur = node.next
+            })
+
+            sum != 60 ? | true { return 1 } | false { }
+            count != 3 ? | true { return 2 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Linked list check {} failed", result.exit_code);
+}
+
+// ============================================================================
+// OUTPUT VERIFICATION TESTS
+// ============================================================================
+
+#[test]
+fn test_println_output() {
+    let source = r#"
+        { io } = @std.io
+
+        main = () i32 {
+            io.println("hello")
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0);
+    assert!(
+        result.stdout.contains("hello"),
+        "Expected 'hello' in output, got: {}",
+        result.stdout
+    );
+}
+
+#[test]
+fn test_print_percent_signs_are_literal() {
+    let source = r#"
+        { io } = @std.io
+
+        main = () i32 {
+            io.print("100% done, %d left")
+            io.println("|")
+            io.eprintln("warn: %s %n")
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0);
+    assert!(
+        result.stdout.contains("100% done, %d left|\n"),
+        "print should not interpret conversions or add a newline, got: {}",
+        result.stdout
+    );
+    assert!(
+        result.stderr.contains("warn: %s %n\n"),
+        "eprintln should write the text as-is to stderr, got: {}",
+        result.stderr
+    );
+}
+
+#[test]
+fn test_string_interpolation_output() {
+    let source = r#"
+        { io } = @std.io
+
+        main = () i32 {
+            x = 42
+            io.println("value: ${x}")
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0);
+    assert!(
+        result.stdout.contains("value: 42"),
+        "Expected 'value: 42' in output, got: {}",
+        result.stdout
+    );
+}
+
+/// Bools returned from calls print as true/false, not as integers
+#[test]
+fn test_string_interpolation_of_bool_call() {
+    let source = r#"
+        { io } = @std.io
+
+        is_even = (n: i64) bool {
+            return n % 2 == 0
+        }
+
+        main = () i32 {
+            io.println("four: ${is_even(4)}")
+            io.println("five: ${is_even(5)}")
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0);
+    assert!(result.stdout.contains("four: true"), "Expected 'four: true', got: {}", result.stdout);
+    assert!(result.stdout.contains("five: false"), "Expected 'five: false', got: {}", result.stdout);
+}
+
+/// f32 values are widened to double before reaching the varargs formatter
+#[test]
+fn test_string_interpolation_of_f32() {
+    let source = r#"
+        { io } = @std.io
+
+        main = () i32 {
+            x: f32 = 2.5
+            y: f32 = 0.1
+            io.println("f32: ${x} ${y}")
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0);
+    assert!(
+        result.stdout.contains("f32: 2.500000 0.100000"),
+        "Expected 'f32: 2.500000 0.100000' in output, got: {}",
+        result.stdout
+    );
+}
+
+/// print_int writes no newline, so consecutive calls run together
+#[test]
+fn test_print_int_has_no_newline() {
+    let source = r#"
+        { io } = @std.io
+
+        main = () i32 {
+            io.print_int(12)
+            io.print_int(-345)
+            io.print_int(0)
+            io.println("")
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0);
+    assert_eq!(result.stdout, "12-3450\n", "Expected '12-3450' on one line, got: {:?}", result.stdout);
+}
+
+/// Buffered stdout keeps many small prints in order through a single flush
+#[test]
+fn test_buffered_print_many_values() {
+    let source = r#"
+        { io } = @std.io
+
+        main = () i32 {
+            i ::= 0
+            loop(() {
+                i >= 10000 ?
+                | true { break }
+                | false {
+                    io.print_int(i)
+                    io.print("\n")
+                    i = i + 1
+                }
+            })
+            io.flush()
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0);
+    let lines: Vec<&str> = result.stdout.lines().collect();
+    assert_eq!(lines.len(), 10000, "Expected 10000 lines of output");
+    for (i, line) in lines.iter().enumerate() {
+        assert_eq!(*line, i.to_string(), "Line {} is out of order", i);
+    }
+}
+
+/// process.exit flushes buffered stdout before the raw exit syscall
+#[test]
+fn test_process_exit_flushes_stdout() {
+    let source = r#"
+        { io } = @std.io
+        { exit } = @std.sys.process.process
+
+        main = () i32 {
+            io.println("before exit")
+            exit(3)
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 3, "stderr: {}", result.stderr);
+    assert_eq!(result.stdout, "before exit\n", "Buffered output was lost on exit");
+}
+
+#[test]
+fn test_print_float_with_precision() {
+    let source = r#"
+        { io } = @std.io
+
+        main = () i32 {
+            io.print_float_prec(3.14159, 2)
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0);
+    assert_eq!(
+        result.stdout, "3.14",
+        "Expected '3.14' in output, got: {}",
+        result.stdout
+    );
+}
+
+#[test]
+fn test_print_float_scientific() {
+    let source = r#"
+        { io } = @std.io
+
+        main = () i32 {
+            io.print_float_sci(0.00001234)
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0);
+    assert_eq!(
+        result.stdout, "1.234000e-05",
+        "Expected '1.234000e-05' in output, got: {}",
+        result.stdout
+    );
+}
+
+// ============================================================================
+// VARIABLE DECLARATION TESTS - All 6 documented forms
+// ============================================================================
+
+/// Test immutable variable with type inference: x = 10
+#[test]
+fn test_variable_immutable_inferred() {
+    let source = r#"
+        main = () i32 {
+            x = 42
+            x == 42 ?
+                | true { return 0 }
+                | false { return 1 }
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Immutable inferred variable failed");
+}
+
+/// Test immutable variable with explicit type: x: i32 = 10
+#[test]
+fn test_variable_immutable_explicit_type() {
+    let source = r#"
+        main = () i32 {
+            x: i64 = 100
+            x == 100 ?
+                | true { return 0 }
+                | false { return 1 }
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Immutable explicit type variable failed");
+}
+
+/// Test mutable variable with inference: x ::= 10
+#[test]
+fn test_variable_mutable_inferred() {
+    let source = r#"
+        main = () i32 {
+            x ::= 5
+            x = x + 10
+            x == 15 ?
+                | true { return 0 }
+                | false { return 1 }
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Mutable inferred variable failed");
+}
+
+/// Test mutable variable with explicit type: x:: i32 = 10
+#[test]
+fn test_variable_mutable_explicit() {
+    let source = r#"
+        main = () i32 {
+            x:: i32 = 20
+            x = x * 2
+            x == 40 ?
+                | true { return 0 }
+                | false { return 1 }
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Mutable explicit type variable failed");
+}
+
+// ============================================================================
+// UFC (UNIFORM FUNCTION CALL) TESTS
+// ============================================================================
+
+/// Test basic UFC - function called as method
+#[test]
+fn test_ufc_basic() {
+    let source = r#"
+        double = (n: i32) i32 {
+            return n * 2
+        }
+
+        main = () i32 {
+            // Traditional call
+            a = double(5)
+            // UFC call
+            b = 5.double()
+
+            (a == 10) ?
+                | true {
+                    (b == 10) ?
+                        | true { return 0 }
+                        | false { return 2 }
+                }
+                | false { return 1 }
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "UFC basic call failed");
+}
+
+/// Test UFC chaining - multiple UFC calls in sequence
+#[test]
+fn test_ufc_chaining() {
+    let source = r#"
+        double = (n: i32) i32 { return n * 2 }
+        add_one = (n: i32) i32 { return n + 1 }
+
+        main = () i32 {
+            // Chain: 5.double() = 10, 10.add_one() = 11, 11.double() = 22
+            result = 5.double().add_one().double()
+            result == 22 ?
+                | true { return 0 }
+                | false { return 1 }
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "UFC chaining failed");
+}
+
+// ============================================================================
+// FLOAT ARITHMETIC TESTS
+// ============================================================================
+
+/// Test f64 basic arithmetic via function parameters
+#[test]
+fn test_float64_arithmetic() {
+    let source = r#"
+        add_floats = (a: f64, b: f64) f64 {
+            return a + b
+        }
+
+        main = () i32 {
+            sum = add_floats(10.5, 2.5)
+
+            // Check sum is approximately 13.0
+            (sum > 12.9) ?
+                | true {
+                    (sum < 13.1) ?
+                        | true { return 0 }
+                        | false { return 2 }
+                }
+                | false { return 1 }
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Float64 arithmetic failed");
+}
+
+#[test]
+fn test_math_clamp() {
+    let source = r#"
+        { math } = @std
+
+        main = () i32 {
+            math.clamp(15, 0, 10) != 10 ? | true { return 1 } | false { }
+            math.clamp(-5, 0, 10) != 0 ? | true { return 2 } | false { }
+            math.clamp(7, 0, 10) != 7 ? | true { return 3 } | false { }
+            // Inverted range returns the lower bound
+            math.clamp(7, 10, 0) != 10 ? | true { return 4 } | false { }
+            math.fclamp(2.5, 0.0, 1.0) != 1.0 ? | true { return 5 } | false { }
+            math.fclamp(0.25, 0.0, 1.0) != 0.25 ? | true { return 6 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "math.clamp failed at check {}", result.exit_code);
+}
+
+#[test]
+fn test_import_alias_qualified_call() {
+    let source = r#"
+        { math as m } = @std
+
+        main = () i32 {
+            m.clamp(15, 0, 10) != 10 ? | true { return 1 } | false { }
+            m.fclamp(0.25, 0.0, 1.0) != 0.25 ? | true { return 2 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "aliased math call failed at check {}", result.exit_code);
+}
+
+/// A failing core.assert reached through an alias exits 1 with its message on stderr
+#[test]
+fn test_aliased_core_assert_reports_to_stderr() {
+    let source = r#"
+        { core as c } = @std
+        { io } = @std.io
+
+        main = () i32 {
+            io.println("before")
+            c.assert(1 > 2, "one is not above two")
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 1, "failed assert should exit 1");
+    assert_eq!(result.stdout, "before\n", "assert message leaked into stdout");
+    assert!(result.stderr.contains("one is not above two"), "stderr: {}", result.stderr);
+}
+
+#[test]
+fn test_assert_panics_detects_panicking_closure() {
+    let source = r#"
+        { testing } = @std
+
+        main = () i32 {
+            testing.panics(() { @builtin.panic("boom") }) ? | false { return 1 } | true { }
+            testing.panics(() { }) ? | true { return 2 } | false { }
+            // Passes silently; a closure that returns would print [FAIL] to stderr
+            testing.assert_panics(() { @builtin.panic("boom") })
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "panic detection failed at check {}", result.exit_code);
+    assert!(!result.stderr.contains("[FAIL]"), "stderr: {}", result.stderr);
+}
+
+#[test]
+fn test_math_lerp() {
+    let source = r#"
+        { math } = @std
+
+        main = () i32 {
+            math.lerp(0.0, 10.0, 0.5) != 5.0 ? | true { return 1 } | false { }
+            // Endpoints must be exact, not a + (b - a) * t rounding
+            math.lerp(0.1, 0.7, 1.0) != 0.7 ? | true { return 2 } | false { }
+            math.lerp(0.1, 0.7, 0.0) != 0.1 ? | true { return 3 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "math.lerp failed at check {}", result.exit_code);
+}
+
+#[test]
+fn test_math_gcd_lcm() {
+    let source = r#"
+        { math } = @std
+
+        main = () i32 {
+            math.gcd(12, 18) != 6 ? | true { return 1 } | false { }
+            math.gcd(0, 7) != 7 ? | true { return 2 } | false { }
+            math.gcd(-12, 18) != 6 ? | true { return 3 } | false { }
+            math.lcm(4, 6) != 12 ? | true { return 4 } | false { }
+            math.lcm(-4, 6) != 12 ? | true { return 5 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "math.gcd/lcm failed at check {}", result.exit_code);
+}
+
+/// math.abs keeps the full width of an i64 and dispatches floats to fabs
+#[test]
+fn test_math_abs_i64_and_float() {
+    let source = r#"
+        { math } = @std
+
+        main = () i32 {
+            big: i64 = 0 - 5000000000
+            math.abs(big) != 5000000000 ? | true { return 1 } | false { }
+            math.abs(-7) != 7 ? | true { return 2 } | false { }
+            math.abs(-2.5) != 2.5 ? | true { return 3 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "math.abs failed at check {}", result.exit_code);
+}
+
+#[test]
+fn test_random_seed_is_deterministic() {
+    let source = r#"
+        { io, random } = @std
+
+        main = () i32 {
+            random.seed(42)
+            a = random.next_int()
+            b = random.next_int()
+            c = random.next_int()
+            io.println("${a} ${b} ${c}")
+
+            // Reseeding replays the same sequence
+            random.seed(42)
+            random.next_int() != a ? | true { return 1 } | false { }
+            random.next_int() != b ? | true { return 2 } | false { }
+            random.next_int() != c ? | true { return 3 } | false { }
+
+            f = random.next_float()
+            f < 0.0 || f >= 1.0 ? | true { return 4 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "random reseed check {} failed", result.exit_code);
+    assert!(
+        result
+            .stdout
+            .contains("3127509542104846800 7215036713370752749 7287727928615108923"),
+        "Unexpected xorshift64* sequence for seed 42: {}",
+        result.stdout
+    );
+}
+
+// ============================================================================
+// CORE BIT MANIPULATION TESTS
+// ============================================================================
+
+#[test]
+fn test_core_bit_counting() {
+    let source = r#"
+        { core } = @std
+
+        main = () i32 {
+            core.popcount(0b1011) != 3 ? | true { return 1 } | false { }
+            core.leading_zeros(1) != 63 ? | true { return 2 } | false { }
+            core.leading_zeros32(0x80000000) != 0 ? | true { return 3 } | false { }
+            core.leading_zeros32(0) != 32 ? | true { return 4 } | false { }
+            core.trailing_zeros(8) != 3 ? | true { return 5 } | false { }
+            core.trailing_zeros32(0) != 32 ? | true { return 6 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "core bit counting failed at check {}", result.exit_code);
+}
+
+#[test]
+fn test_core_byte_swap() {
+    let source = r#"
+        { core } = @std
+
+        main = () i32 {
+            core.byte_swap32(0x01020304) != 0x04030201 ? | true { return 1 } | false { }
+            core.byte_swap16(0x1234) != 0x3412 ? | true { return 2 } | false { }
+            core.byte_swap(0x0102030405060708) != 0x0807060504030201 ? | true { return 3 } | false { }
+            // Round-tripping through either byte order is the identity
+            core.byte_swap32(core.byte_swap32(0xDEADBEEF)) != 0xDEADBEEF ? | true { return 4 } | false { }
+            // Exactly one of to_le/to_be is a swap on any host
+            core.to_le32(0x01020304) == core.to_be32(0x01020304) ? | true { return 5 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "core byte swap failed at check {}", result.exit_code);
+}
+
+#[test]
+fn test_hash_fnv1a_and_djb2() {
+    let source = r#"
+        { hash } = @std
+
+        main = () i32 {
+            // FNV-1a("hello") = 0xa430d84680aabd0b
+            h = hash.fnv1a("hello", 5)
+            cast(h, i64) != 0 - 6615550055289275125 ? | true { return 1 } | false { }
+            // Empty input hashes to the offset basis
+            cast(hash.fnv1a("", 0), i64) != 0 - 3750763034362895579 ? | true { return 2 } | false { }
+            hash.djb2("hello", 5) != 210714636441 ? | true { return 3 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "hash check {} failed", result.exit_code);
+}
+
+// ============================================================================
+// HASHMAP TESTS
+// ============================================================================
+
+/// A struct key with its own Hashable implementation is hashed through it
+#[test]
+fn test_hashmap_struct_key_uses_hashable() {
+    let source = r#"
+        { Option } = @std
+        { HashMap } = @std.collections.hashmap
+        { default_gpa } = @std.memory.gpa
+        { Hashable, Comparable } = @std.hash
+
+        Point: {
+            x: i64,
+            y: i64
+        }
+
+        comptime.derive_hashable(Point)
+        comptime.derive_comparable(Point)
+
+        main = () i32 {
+            map ::= HashMap<Point, i64>.new(default_gpa())
+            map.insert(Point { x: 1, y: 2 }, 10)
+            map.insert(Point { x: 2, y: 1 }, 20)
+            map.insert(Point { x: 1, y: 2 }, 30)
+
+            map.len() != 2 ? | true { return 1 } | false { }
+            map.get(Point { x: 1, y: 2 }) ?
+                | Some(v) { v != 30 ? | true { return 2 } | false { } }
+                | None { return 3 }
+            map.get(Point { x: 2, y: 1 }) ?
+                | Some(v) { v != 20 ? | true { return 4 } | false { } }
+                | None { return 5 }
+            map.get(Point { x: 3, y: 3 }) ?
+                | Some(_) { return 6 }
+                | None { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "struct-keyed HashMap check {} failed", result.exit_code);
+}
+
+/// HashMap compares keys with the key type's Comparable impl, not their bytes
+#[test]
+fn test_hashmap_key_equality_uses_comparable() {
+    let source = r#"
+        { Option } = @std
+        { HashMap } = @std.collections.hashmap
+        { default_gpa } = @std.memory.gpa
+        { Hashable, Comparable } = @std.hash
+
+        // Angles a full turn apart are the same key
+        Angle: {
+            deg: i64
+        }
+
+        Angle.implements(Hashable, {
+            hash = (self: Angle) u64 {
+                return cast(self.deg % 360, u64)
+            }
+        })
+
+        Angle.implements(Comparable, {
+            equals = (self: Angle, other: Angle) bool {
+                return self.deg % 360 == other.deg % 360
+            }
+        })
+
+        main = () i32 {
+            map ::= HashMap<Angle, i64>.new(default_gpa())
+            map.insert(Angle { deg: 10 }, 1)
+            map.insert(Angle { deg: 370 }, 2)
+
+            map.len() != 1 ? | true { return 1 } | false { }
+            map.get(Angle { deg: 730 }) ?
+                | Some(v) { v != 2 ? | true { return 2 } | false { } }
+                | None { return 3 }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Comparable key check {} failed", result.exit_code);
+}
+
+/// Inserted values read back through get; re-inserting a key replaces its value
+#[test]
+fn test_hashmap_insert_get_roundtrip() {
+    let source = r#"
+        { Option } = @std
+        { HashMap } = @std.collections.hashmap
+        { default_gpa } = @std.memory.gpa
+
+        main = () i32 {
+            map ::= HashMap<i64, i64>.new(default_gpa())
+            map.get(1) ?
+                | Some(_) { return 1 }
+                | None { }
+
+            map.insert(1, 10)
+            map.insert(2, 20)
+            map.insert(42, 420)
+            map.len() != 3 ? | true { return 2 } | false { }
+
+            map.get(2) ?
+                | Some(v) { v != 20 ? | true { return 3 } | false { } }
+                | None { return 4 }
+            map.get(42) ?
+                | Some(v) { v != 420 ? | true { return 5 } | false { } }
+                | None { return 6 }
+            map.get(7) ?
+                | Some(_) { return 7 }
+                | None { }
+
+            map.insert(1, 11)
+            map.len() != 3 ? | true { return 8 } | false { }
+            map.get(1) ?
+                | Some(v) { v != 11 ? | true { return 9 } | false { } }
+                | None { return 10 }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "HashMap insert/get check {} failed", result.exit_code);
+}
+
+/// Removing returns the value and leaves colliding keys reachable
+#[test]
+fn test_hashmap_remove_returns_value() {
+    let source = r#"
+        { Option } = @std
+        { HashMap } = @std.collections.hashmap
+        { default_gpa } = @std.memory.gpa
+
+        main = () i32 {
+            map ::= HashMap<i64, i64>.new(default_gpa())
+            i ::= 0
+            loop(() {
+                i >= 10 ?
+                | true { break }
+                | false {
+                    map.insert(i, i * 100)
+                    i = i + 1
+                }
+            })
+
+            map.remove(3) ?
+                | Some(v) { v != 300 ? | true { return 1 } | false { } }
+                | None { return 2 }
+            map.get(3) ?
+                | Some(_) { return 3 }
+                | None { }
+            map.remove(3) ?
+                | Some(_) { return 4 }
+                | None { }
+            map.len() != 9 ? | true { return 5 } | false { }
+
+            // Every other key is still retrievable after the removal
+            j ::= 0
+            failed ::= 0
+            loop(() {
+                j >= 10 ?
+                | true { break }
+                | false {
+                    j != 3 ?
+                        | true {
+                            map.get(j) ?
+                                | Some(v) { v != j * 100 ? | true { failed = 1 } | false { } }
+                                | None { failed = 1 }
+                        }
+                        | false { }
+                    j = j + 1
+                }
+            })
+            return failed * 6
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "HashMap.remove check {} failed", result.exit_code);
+}
+
+/// keys_vec/values_vec/entries_vec visit every entry exactly once
+#[test]
+fn test_hashmap_collect_keys_values_entries() {
+    let source = r#"
+        { Option } = @std
+        { HashMap } = @std.collections.hashmap
+        { default_gpa } = @std.memory.gpa
+
+        main = () i32 {
+            map ::= HashMap<i64, i64>.new(default_gpa())
+            map.insert(1, 10)
+            map.insert(2, 20)
+            map.insert(3, 30)
+
+            // Iteration order is unspecified, so compare order-independent aggregates
+            keys = map.keys_vec()
+            keys.len() != 3 ? | true { return 1 } | false { }
+            key_sum ::= 0
+            key_product ::= 1
+            i ::= 0
+            loop(() {
+                i >= keys.len() ?
+                | true { break }
+                | false {
+                    keys.get(i) ?
+                        | Some(k) {
+                            key_sum = key_sum + k
+                            key_product = key_product * k
+                        }
+                        | None { }
+                    i = i + 1
+                }
+            })
+            key_sum != 6 || key_product != 6 ? | true { return 2 } | false { }
+
+            values = map.values_vec()
+            values.len() != 3 ? | true { return 3 } | false { }
+
+            entries = map.entries_vec()
+            entries.len() != 3 ? | true { return 4 } | false { }
+            j ::= 0
+            mismatched ::= 0
+            loop(() {
+                j >= entries.len() ?
+                | true { break }
+                | false {
+                    entries.get(j) ?
+                        | Some(pair) { pair.value != pair.key * 10 ? | true { mismatched = 1 } | false { } }
+                        | None { mismatched = 1 }
+                    j = j + 1
+                }
+            })
+            return mismatched * 5
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "HashMap collect check {} failed", result.exit_code);
+}
+
+/// contains_key answers presence without going through get's Option
+#[test]
+fn test_hashmap_contains_key() {
+    let source = r#"
+        { HashMap } = @std.collections.hashmap
+        { default_gpa } = @std.memory.gpa
+
+        main = () i32 {
+            map ::= HashMap<i64, i64>.new(default_gpa())
+            map.contains_key(1) ? | true { return 1 } | false { }
+
+            map.insert(1, 100)
+            map.insert(2, 200)
+            map.insert(3, 300)
+            map.remove(2)
+
+            map.contains_key(1) ? | true { } | false { return 2 }
+            map.contains_key(2) ? | true { return 3 } | false { }
+            map.contains_key(3) ? | true { } | false { return 4 }
+            map.contains_key(4) ? | true { return 5 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "HashMap.contains_key check {} failed", result.exit_code);
+}
+
+/// Inserting past the load factor grows the table and keeps every key reachable
+#[test]
+fn test_hashmap_grows_and_rehashes() {
+    let source = r#"
+        { Option } = @std
+        { HashMap } = @std.collections.hashmap
+        { default_gpa } = @std.memory.gpa
+
+        main = () i32 {
+            map ::= HashMap<i64, i64>.new(default_gpa())
+            i ::= 0
+            loop(() {
+                i >= 100 ?
+                | true { break }
+                | false {
+                    map.insert(i, i * 7)
+                    i = i + 1
+                }
+            })
+
+            map.len() != 100 ? | true { return 1 } | false { }
+            map.capacity <= 16 ? | true { return 2 } | false { }
+
+            j ::= 0
+            failed ::= 0
+            loop(() {
+                j >= 100 ?
+                | true { break }
+                | false {
+                    map.get(j) ?
+                        | Some(v) { v != j * 7 ? | true { failed = 1 } | false { } }
+                        | None { failed = 1 }
+                    j = j + 1
+                }
+            })
+            return failed * 3
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "HashMap growth check {} failed", result.exit_code);
+}
+
+/// add reports whether the element is new, and duplicates are stored once
+#[test]
+fn test_hashset_add_contains_remove() {
+    let source = r#"
+        { HashSet } = @std.collections.hashset
+        { default_gpa } = @std.memory.gpa
+
+        main = () i32 {
+            set ::= HashSet<i64>.new(default_gpa())
+            set.add(5) != true ? | true { return 1 } | false { }
+            set.add(9) != true ? | true { return 2 } | false { }
+            set.add(5) != false ? | true { return 3 } | false { }
+            set.len() != 2 ? | true { return 4 } | false { }
+
+            set.contains(5) != true ? | true { return 5 } | false { }
+            set.contains(7) != false ? | true { return 6 } | false { }
+
+            set.remove(5) != true ? | true { return 7 } | false { }
+            set.remove(5) != false ? | true { return 8 } | false { }
+            set.contains(5) != false ? | true { return 9 } | false { }
+            set.contains(9) != true ? | true { return 10 } | false { }
+            set.len() != 1 ? | true { return 11 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "HashSet check {} failed", result.exit_code);
+}
+
+// ============================================================================
+// VEC TESTS
+// ============================================================================
+
+#[test]
+fn test_vec_insert_at_and_remove_at() {
+    let source = r#"
+        { Option } = @std
+        { Vec } = @std.collections.vec
+        { default_gpa } = @std.memory.gpa
+
+        at = (v: Vec<i64>, i: usize) i64 {
+            v.get(i) ?
+                | Some(x) { return x }
+                | None { return -1 }
+        }
+
+        main = () i32 {
+            v ::= Vec<i64>.new(default_gpa())
+            v.push(10)
+            v.push(30)
+
+            // [10, 30] -> [10, 20, 30]
+            v.insert_at(1, 20) ? | true { } | false { return 1 }
+            v.len() != 3 ? | true { return 2 } | false { }
+            at(v, 0) != 10 || at(v, 1) != 20 || at(v, 2) != 30 ? | true { return 3 } | false { }
+
+            // Out-of-bounds insert is rejected
+            v.insert_at(5, 99) ? | true { return 4 } | false { }
+
+            // [10, 20, 30] -> [20, 30]
+            v.remove_at(0) ?
+                | Some(x) { x != 10 ? | true { return 5 } | false { } }
+                | None { return 6 }
+            v.len() != 2 ? | true { return 7 } | false { }
+            at(v, 0) != 20 || at(v, 1) != 30 ? | true { return 8 } | false { }
+
+            v.remove_at(2) ?
+                | Some(_) { return 9 }
+                | None { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Vec insert_at/remove_at check {} failed", result.exit_code);
+}
+
+#[test]
+fn test_vec_clear_keeps_capacity() {
+    let source = r#"
+        { Vec } = @std.collections.vec
+        { default_gpa } = @std.memory.gpa
+
+        main = () i32 {
+            v ::= Vec<i64>.new(default_gpa())
+            v.push(1)
+            v.push(2)
+            v.push(3)
+            cap = v.capacity()
+            cap < 3 ? | true { return 1 } | false { }
+
+            v.clear()
+            v.len() != 0 ? | true { return 2 } | false { }
+            v.capacity() != cap ? | true { return 3 } | false { }
+
+            // Buffer is reused after clear
+            v.push(42)
+            v.len() != 1 ? | true { return 4 } | false { }
+            v.capacity() != cap ? | true { return 5 } | false { }
+            v.get(0) ?
+                | Some(x) { x != 42 ? | true { return 6 } | false { } }
+                | None { return 7 }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Vec clear/capacity check {} failed", result.exit_code);
+}
+
+#[test]
+fn test_vec_extend() {
+    let source = r#"
+        { Vec } = @std.collections.vec
+        { default_gpa } = @std.memory.gpa
+
+        at = (v: Vec<i64>, i: usize) i64 {
+            v.get(i) ?
+                | Some(x) { return x }
+                | None { return -1 }
+        }
+
+        main = () i32 {
+            a ::= Vec<i64>.new(default_gpa())
+            a.push(1)
+            a.push(2)
+
+            b ::= Vec<i64>.new(default_gpa())
+            b.push(3)
+            b.push(4)
+            b.push(5)
+
+            a.extend(b)
+            a.len() != 5 ? | true { return 1 } | false { }
+            i ::= 0
+            loop(() {
+                i >= 5 ?
+                    | true { break }
+                    | false {
+                        at(a, i) != i + 1 ? | true { return 2 } | false { }
+                        i = i + 1
+                    }
+            })
+
+            // Source vector is left untouched
+            b.len() != 3 ? | true { return 3 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Vec extend check {} failed", result.exit_code);
+}
+
+#[test]
+fn test_vec_extend_from_slice() {
+    let source = r#"
+        { compiler } = @std
+        { Vec } = @std.collections.vec
+        { Ptr } = @std.core.ptr
+        { default_gpa } = @std.memory.gpa
+
+        at = (v: Vec<i64>, i: usize) i64 {
+            v.get(i) ?
+                | Some(x) { return x }
+                | None { return -1 }
+        }
+
+        main = () i32 {
+            a ::= Vec<i64>.new(default_gpa())
+            a.push(1)
+            a.push(2)
+
+            // A plain array of three i64s
+            items = compiler.raw_allocate(24)
+            compiler.store<i64>(items, 3)
+            compiler.store<i64>(items.offset(8), 4)
+            compiler.store<i64>(items.offset(16), 5)
+
+            a.extend_from_slice(Ptr<i64>.from_addr(items), 3)
+            a.len() != 5 ? | true { return 1 } | false { }
+            i ::= 0
+            loop(() {
+                i >= 5 ?
+                    | true { break }
+                    | false {
+                        at(a, i) != i + 1 ? | true { return 2 } | false { }
+                        i = i + 1
+                    }
+            })
+
+            // An empty slice leaves the vector alone
+            a.extend_from_slice(Ptr<i64>.from_addr(items), 0)
+            a.len() != 5 ? | true { return 3 } | false { }
+            compiler.raw_deallocate(items, 24)
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Vec extend_from_slice check {} failed", result.exit_code);
+}
+
+#[test]
+fn test_vec_sort_with_comparator() {
+    let source = r#"
+        { Vec } = @std.collections.vec
+        { default_gpa } = @std.memory.gpa
+
+        at = (v: Vec<i64>, i: usize) i64 {
+            v.get(i) ?
+                | Some(x) { return x }
+                | None { return -1 }
+        }
+
+        main = () i32 {
+            v ::= Vec<i64>.new(default_gpa())
+            v.push(3)
+            v.push(1)
+            v.push(2)
+
+            v.sort((a: i64, b: i64) { a - b })
+            at(v, 0) != 1 || at(v, 1) != 2 || at(v, 2) != 3 ? | true { return 1 } | false { }
+
+            v.sort((a: i64, b: i64) { b - a })
+            at(v, 0) != 3 || at(v, 1) != 2 || at(v, 2) != 1 ? | true { return 2 } | false { }
+
+            v.len() != 3 ? | true { return 3 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Vec sort check {} failed", result.exit_code);
+}
+
+#[test]
+fn test_vec_binary_search() {
+    let source = r#"
+        { Vec } = @std.collections.vec
+        { default_gpa } = @std.memory.gpa
+
+        main = () i32 {
+            v ::= Vec<i64>.new(default_gpa())
+            v.push(1)
+            v.push(3)
+            v.push(5)
+            v.push(7)
+            v.push(9)
+
+            v.binary_search(5, (a: i64, b: i64) { a - b }) ?
+                | Some(i) { i != 2 ? | true { return 1 } | false { } }
+                | None { return 2 }
+
+            v.binary_search(4, (a: i64, b: i64) { a - b }) ?
+                | Some(_) { return 3 }
+                | None { }
+
+            v.binary_search(9, (a: i64, b: i64) { a - b }) ?
+                | Some(i) { i != 4 ? | true { return 4 } | false { } }
+                | None { return 5 }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Vec binary_search check {} failed", result.exit_code);
+}
+
+#[test]
+fn test_vec_index_of_and_contains() {
+    let source = r#"
+        { Vec } = @std.collections.vec
+        { default_gpa } = @std.memory.gpa
+
+        main = () i32 {
+            v ::= Vec<i64>.new(default_gpa())
+            v.push(1)
+            v.push(2)
+            v.push(3)
+
+            v.index_of(3) ?
+                | Some(i) { i != 2 ? | true { return 1 } | false { } }
+                | None { return 2 }
+
+            v.index_of(7) ?
+                | Some(_) { return 3 }
+                | None { }
+
+            v.contains(2) ? | false { return 4 } | true { }
+            v.contains(9) ? | true { return 5 } | false { }
+
+            names ::= Vec<StaticString>.new(default_gpa())
+            names.push("ann")
+            names.push("bob")
+            names.index_of("bob") ?
+                | Some(i) { i != 1 ? | true { return 6 } | false { } }
+                | None { return 7 }
+            names.contains("eve") ? | true { return 8 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Vec index_of/contains check {} failed", result.exit_code);
+}
+
+#[test]
+fn test_vec_slice_copies_range() {
+    let source = r#"
+        { Vec } = @std.collections.vec
+        { default_gpa } = @std.memory.gpa
+
+        at = (v: Vec<i64>, i: usize) i64 {
+            v.get(i) ?
+                | Some(x) { return x }
+                | None { return -1 }
+        }
+
+        main = () i32 {
+            v ::= Vec<i64>.new(default_gpa())
+            v.push(10)
+            v.push(20)
+            v.push(30)
+            v.push(40)
+
+            v.slice(1, 3) ?
+                | Some(s) {
+                    s.len() != 2 ? | true { return 1 } | false { }
+                    at(s, 0) != 20 || at(s, 1) != 30 ? | true { return 2 } | false { }
+                }
+                | None { return 3 }
+
+            v.slice(2, 5) ?
+                | Some(_) { return 4 }
+                | None { }
+            v.slice(3, 1) ?
+                | Some(_) { return 5 }
+                | None { }
+            v.len() != 4 ? | true { return 6 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Vec slice check {} failed", result.exit_code);
+}
+
+#[test]
+fn test_vec_reverse() {
+    let source = r#"
+        { Vec } = @std.collections.vec
+        { default_gpa } = @std.memory.gpa
+
+        at = (v: Vec<i64>, i: usize) i64 {
+            v.get(i) ?
+                | Some(x) { return x }
+                | None { return -1 }
+        }
+
+        main = () i32 {
+            even ::= Vec<i64>.new(default_gpa())
+            even.push(1)
+            even.push(2)
+            even.push(3)
+            even.push(4)
+            even.reverse()
+            at(even, 0) != 4 || at(even, 1) != 3 || at(even, 2) != 2 || at(even, 3) != 1 ? | true { return 1 } | false { }
+
+            // Odd length: the middle element stays put
+            odd ::= Vec<i64>.new(default_gpa())
+            odd.push(5)
+            odd.push(6)
+            odd.push(7)
+            odd.reverse()
+            at(odd, 0) != 7 || at(odd, 1) != 6 || at(odd, 2) != 5 ? | true { return 2 } | false { }
+
+            odd.len() != 3 ? | true { return 3 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Vec reverse check {} failed", result.exit_code);
+}
+
+// ============================================================================
+// COMPARISON OPERATOR TESTS
+// ============================================================================
+
+/// Test less than operator
+#[test]
+fn test_comparison_less_than() {
+    let source = r#"
+        main = () i32 {
+            (5 < 10) ?
+                | true { return 0 }
+                | false { return 1 }
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Less than comparison failed");
+}
+
+/// Test greater than operator
+#[test]
+fn test_comparison_greater_than() {
+    let source = r#"
+        main = () i32 {
+            (10 > 5) ?
+                | true { return 0 }
+                | false { return 1 }
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Greater than comparison failed");
+}
+
+/// Test not equal operator
+#[test]
+fn test_comparison_not_equal() {
     let source = r#"
         main = () i32 {
             (5 != 10) ?
@@ -716,6 +2459,24 @@ fn test_comparison_greater_equal() {
     assert_eq!(result.exit_code, 0, "Greater than or equal comparison failed");
 }
 
+/// Test logical not on bools, including double negation and comparisons
+#[test]
+fn test_logical_not() {
+    let source = r#"
+        main = () i32 {
+            t = true
+            !true ? | true { return 1 } | false { }
+            !t == false ? | false { return 2 } | true { }
+            !!t ? | false { return 3 } | true { }
+            !(3 > 5) ? | false { return 4 } | true { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Logical not check {} failed", result.exit_code);
+}
+
 // ============================================================================
 // LOOP TESTS
 // ============================================================================
@@ -761,6 +2522,46 @@ fn test_loop_counter() {
     assert_eq!(result.exit_code, 0, "Loop counter failed");
 }
 
+/// Test ++ and -- statements on a loop counter
+#[test]
+fn test_loop_increment_decrement() {
+    let source = r#"
+        main = () i32 {
+            up ::= 0
+            down ::= 10
+            loop(() {
+                up++
+                down--
+                (up == 10) ? { break }
+            })
+            up != 10 ? | true { return 1 } | false { }
+            down != 0 ? | true { return 2 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Increment/decrement check {} failed", result.exit_code);
+}
+
+/// Test `--` between operands still means subtracting a negation
+#[test]
+fn test_double_minus_between_operands() {
+    let source = r#"
+        main = () i32 {
+            x = 5
+            y = 2
+            x--1 != 6 ? | true { return 1 } | false { }
+            x--y != 7 ? | true { return 2 } | false { }
+            x - -y != 7 ? | true { return 3 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Double minus check {} failed", result.exit_code);
+}
+
 // ============================================================================
 // ENUM TESTS
 // ============================================================================
@@ -813,3 +2614,470 @@ fn test_enum_multiple_branches() {
     let result = run_expecting_success(source);
     assert_eq!(result.exit_code, 0, "Enum multiple branches failed");
 }
+
+/// Test repr(i32) enum passed to an extern function as a plain integer
+#[test]
+fn test_repr_enum_passed_to_extern() {
+    let source = r#"
+        Level: repr(i32) Low, Medium, High
+
+        abs: (n: i32) i32
+
+        main = () i32 {
+            lvl = Level.High
+            abs(lvl) != 2 ? | true { return 1 } | false { }
+            abs(Level.Medium) != 1 ? | true { return 2 } | false { }
+            abs(Level.Low) != 0 ? | true { return 3 } | false { }
+
+            // repr enums still pattern match like any other enum
+            lvl ?
+                | .High { return 0 }
+                | .Medium { return 4 }
+                | .Low { return 5 }
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "repr enum FFI check {} failed", result.exit_code);
+}
+
+/// Test repr(i32) enum returned from an extern function
+#[test]
+fn test_repr_enum_returned_from_extern() {
+    let source = r#"
+        Level: repr(i32) Low, Medium, High
+
+        abs: (n: i32) Level
+
+        main = () i32 {
+            lvl = abs(-2)
+            lvl ?
+                | .High { }
+                | .Medium { return 1 }
+                | .Low { return 2 }
+            abs(1) ?
+                | .Medium { return 0 }
+                | .High { return 3 }
+                | .Low { return 4 }
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "repr enum FFI return check {} failed", result.exit_code);
+}
+
+// ============================================================================
+// COMPTIME TESTS
+// ============================================================================
+
+/// Test comptime.assert: true conditions compile, false ones fail with the message
+#[test]
+fn test_comptime_assert() {
+    let passing = r#"
+        comptime.assert(2 + 2 == 4, "arithmetic is broken")
+
+        main = () i32 {
+            comptime.assert(1 < 2, "ordering is broken")
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(passing);
+    assert_eq!(result.exit_code, 0, "Passing comptime.assert should compile");
+
+    let failing = r#"
+        comptime.assert(1 == 2, "one is not two")
+
+        main = () i32 {
+            return 0
+        }
+    "#;
+
+    let err = compile_and_run(failing).expect_err("False comptime.assert should fail compilation");
+    assert!(err.contains("one is not two"), "Unexpected error: {}", err);
+}
+
+/// Test a comptime.assert nested in a match arm is still evaluated
+#[test]
+fn test_comptime_assert_in_match_arm() {
+    let failing = r#"
+        main = () i32 {
+            x = 1
+            x == 1 ?
+                | true {
+                    comptime.assert(1 == 2, "assert inside arm")
+                    return 0
+                }
+                | false { return 1 }
+        }
+    "#;
+
+    let err = compile_and_run(failing).expect_err("False comptime.assert in a match arm should fail compilation");
+    assert!(err.contains("assert inside arm"), "Unexpected error: {}", err);
+}
+
+/// Test comptime.field_count reads a struct's declared field count
+#[test]
+fn test_comptime_field_introspection() {
+    let source = r#"
+        Point3: {
+            x: i64,
+            y: i64,
+            z: i64,
+        }
+
+        comptime.assert(comptime.field_count(Point3) == 3, "Point3 should have three fields")
+
+        main = () i32 {
+            count = comptime.field_count(Point3)
+            count != 3 ? | true { return 1 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Comptime introspection check {} failed", result.exit_code);
+}
+
+/// Test comptime.field_count folds when it is a match scrutinee
+#[test]
+fn test_comptime_field_count_as_scrutinee() {
+    let source = r#"
+        Pair: {
+            a: i64,
+            b: i64,
+        }
+
+        main = () i32 {
+            comptime.field_count(Pair) ?
+                | 2 { return 0 }
+                | _ { return 1 }
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "field_count scrutinee check {} failed", result.exit_code);
+}
+
+/// Test comptime.derive_clone generates a field-by-field copy
+#[test]
+fn test_comptime_derive_clone() {
+    let source = r#"
+        Inner: {
+            a: i64,
+            b: i64,
+        }
+
+        Outer: {
+            id: i64,
+            inner: Inner,
+        }
+
+        comptime.derive_clone(Outer)
+
+        main = () i32 {
+            original ::= Outer { id: 7, inner: Inner { a: 1, b: 2 } }
+            copy = original.clone()
+            copy.id != 7 ? | true { return 1 } | false { }
+            copy.inner.a != 1 ? | true { return 2 } | false { }
+            copy.inner.b != 2 ? | true { return 3 } | false { }
+
+            // Mutating the original leaves the clone untouched
+            p = original.mut_ref()
+            p.val.id = 99
+            original.id != 99 ? | true { return 4 } | false { }
+            copy.id != 7 ? | true { return 5 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Derived clone check {} failed", result.exit_code);
+}
+
+/// Test comptime.derive_deep_clone gives Vec fields their own buffer
+#[test]
+fn test_comptime_derive_deep_clone() {
+    let source = r#"
+        { Vec } = @std.collections.vec
+        { Allocator } = @std.memory.allocator
+        { default_gpa } = @std.memory.gpa
+
+        Bag: {
+            id: i64,
+            items: Vec<i64>,
+        }
+
+        comptime.derive_deep_clone(Bag)
+
+        first = (v: Vec<i64>) i64 {
+            v.get(0) ?
+                | Some(x) { return x }
+                | None { return -1 }
+        }
+
+        main = () i32 {
+            items ::= Vec<i64>.new(default_gpa())
+            items.push(1)
+            items.push(2)
+            original = Bag { id: 7, items: items }
+
+            copy = original.clone(default_gpa())
+            copy.id != 7 ? | true { return 1 } | false { }
+            copy.items.len() != 2 ? | true { return 2 } | false { }
+
+            // Shift the clone's buffer; the original's must not move
+            copied ::= copy.items
+            copied.remove_at(0)
+            first(copied) != 2 ? | true { return 3 } | false { }
+            first(original.items) != 1 ? | true { return 4 } | false { }
+            original.items.len() != 2 ? | true { return 5 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Derived deep clone check {} failed", result.exit_code);
+}
+
+/// Test comptime.derive_deep_clone copies pointer targets and map storage
+#[test]
+fn test_comptime_derive_deep_clone_pointer_and_map_fields() {
+    let source = r#"
+        { Option } = @std
+        { HashMap } = @std.collections.hashmap
+        { Allocator } = @std.memory.allocator
+        { default_gpa } = @std.memory.gpa
+
+        Counter: {
+            count: MutPtr<i64>,
+            seen: HashMap<i64, i64>,
+        }
+
+        comptime.derive_deep_clone(Counter)
+
+        main = () i32 {
+            count = default_gpa().allocate(8) as MutPtr<i64>
+            count.val = 5
+            seen ::= HashMap<i64, i64>.new(default_gpa())
+            seen.insert(1, 10)
+            original = Counter { count: count, seen: seen }
+
+            copy = original.clone(default_gpa())
+            copy.count.val != 5 ? | true { return 1 } | false { }
+
+            // Writes through the clone stay in the clone
+            copy.count.val = 9
+            original.count.val != 5 ? | true { return 2 } | false { }
+            copied ::= copy.seen
+            copied.insert(1, 20)
+            original.seen.get(1) ?
+                | Some(v) { v != 10 ? | true { return 3 } | false { } }
+                | None { return 4 }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Deep clone pointer check {} failed", result.exit_code);
+}
+
+/// Test comptime.variants drives a generated enum-to-string method
+#[test]
+fn test_comptime_enum_variants() {
+    let source = r#"
+        Color: Red, Green, Blue
+
+        comptime.derive_name(Color)
+
+        main = () i32 {
+            names = comptime.variants(Color)
+            names[1] != "Green" ? | true { return 1 } | false { }
+
+            r = Color.Red
+            r.name() != "Red" ? | true { return 2 } | false { }
+            b = Color.Blue
+            b.name() != "Blue" ? | true { return 3 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Enum variant name check {} failed", result.exit_code);
+}
+
+/// Test file_size_at reports the length of a written file and errors for a missing path
+#[test]
+fn test_file_size_at() {
+    let source = r#"
+        { String } = @std.collections.string
+        { Vec } = @std.collections.vec
+        { Allocator } = @std.memory.allocator
+        { default_gpa } = @std.memory.gpa
+        { write_file, file_size_at, unlink } = @std.io.files.file
+
+        repeat_byte = (byte: u8, count: i64, alloc: Allocator) Vec<u8> {
+            v ::= Vec<u8>.new(alloc)
+            i ::= 0
+            loop(() {
+                i >= count ? | true { break } | false { }
+                v.push(byte)
+                i = i + 1
+            })
+            return v
+        }
+
+        main = () i32 {
+            alloc = default_gpa()
+            path = String.from("/tmp/zen_test_file_size_at.txt", alloc)
+            letter: u8 = 104
+
+            write_file(path, repeat_byte(letter, 5, alloc), alloc) ?
+                | Err(_) { return 1 }
+                | Ok(_) { }
+            file_size_at(path, alloc) ?
+                | Err(_) { return 2 }
+                | Ok(size) { size != 5 ? | true { return 3 } | false { } }
+
+            _ = unlink(path, alloc)
+            file_size_at(path, alloc) ?
+                | Err(_) { }
+                | Ok(_) { return 4 }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "file_size_at check {} failed", result.exit_code);
+}
+
+/// Test append_file adds to the end of a file and creates a missing one
+#[test]
+fn test_append_file() {
+    let source = r#"
+        { String } = @std.collections.string
+        { Vec } = @std.collections.vec
+        { Allocator } = @std.memory.allocator
+        { default_gpa } = @std.memory.gpa
+        { write_file, append_file, read_file, file_exists, unlink } = @std.io.files.file
+
+        repeat_byte = (byte: u8, count: i64, alloc: Allocator) Vec<u8> {
+            v ::= Vec<u8>.new(alloc)
+            i ::= 0
+            loop(() {
+                i >= count ? | true { break } | false { }
+                v.push(byte)
+                i = i + 1
+            })
+            return v
+        }
+
+        byte_at = (v: Vec<u8>, i: usize) i64 {
+            v.get(i) ?
+                | Some(b) { return b as i64 }
+                | None { return -1 }
+        }
+
+        main = () i32 {
+            alloc = default_gpa()
+            path = String.from("/tmp/zen_test_append_file.txt", alloc)
+            a: u8 = 97
+            b: u8 = 98
+            c: u8 = 99
+
+            // "aa" + "bbb" + "c"
+            write_file(path, repeat_byte(a, 2, alloc), alloc) ? | Err(_) { return 1 } | Ok(_) { }
+            append_file(path, repeat_byte(b, 3, alloc), alloc) ? | Err(_) { return 2 } | Ok(_) { }
+            append_file(path, repeat_byte(c, 1, alloc), alloc) ? | Err(_) { return 3 } | Ok(_) { }
+            read_file(path, alloc) ?
+                | Err(_) { return 4 }
+                | Ok(contents) {
+                    contents.len() != 6 ? | true { return 5 } | false { }
+                    byte_at(contents, 0) != 97 || byte_at(contents, 1) != 97 ? | true { return 6 } | false { }
+                    byte_at(contents, 2) != 98 || byte_at(contents, 4) != 98 ? | true { return 7 } | false { }
+                    byte_at(contents, 5) != 99 ? | true { return 8 } | false { }
+                }
+
+            // Appending to a missing file creates it
+            _ = unlink(path, alloc)
+            file_exists(path, alloc) ? | true { return 9 } | false { }
+            append_file(path, repeat_byte(c, 1, alloc), alloc) ? | Err(_) { return 10 } | Ok(_) { }
+            read_file(path, alloc) ?
+                | Err(_) { return 11 }
+                | Ok(contents) { contents.len() != 1 ? | true { return 12 } | false { } }
+
+            _ = unlink(path, alloc)
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "append_file check {} failed", result.exit_code);
+}
+
+/// Test rename moves a file: the old path is gone and the new one holds its contents
+#[test]
+fn test_rename_file() {
+    let source = r#"
+        { String } = @std.collections.string
+        { Vec } = @std.collections.vec
+        { default_gpa } = @std.memory.gpa
+        { write_file, read_file, rename, file_exists, unlink } = @std.io.files.file
+
+        main = () i32 {
+            alloc = default_gpa()
+            old_path = String.from("/tmp/zen_test_rename_old.txt", alloc)
+            new_path = String.from("/tmp/zen_test_rename_new.txt", alloc)
+            data ::= Vec<u8>.new(alloc)
+            z: u8 = 122
+            data.push(z)
+            data.push(z)
+            data.push(z)
+
+            write_file(old_path, data, alloc) ? | Err(_) { return 1 } | Ok(_) { }
+            rename(old_path, new_path, alloc) ? | Err(_) { return 2 } | Ok(_) { }
+
+            file_exists(old_path, alloc) ? | true { return 3 } | false { }
+            read_file(new_path, alloc) ?
+                | Err(_) { return 4 }
+                | Ok(contents) { contents.len() != 3 ? | true { return 5 } | false { } }
+
+            // Renaming a path that no longer exists is an error
+            rename(old_path, new_path, alloc) ? | Err(_) { } | Ok(_) { return 6 }
+
+            _ = unlink(new_path, alloc)
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "rename check {} failed", result.exit_code);
+}
+
+/// Test user comptime code can loop over comptime.variants to build values
+#[test]
+fn test_comptime_loop_over_variants() {
+    let source = r#"
+        Direction: North, East, South, West
+
+        comptime {
+            joined ::= ""
+            count ::= 0
+            comptime.variants(Direction).loop((name) {
+                joined = joined + name
+                count = count + 1
+            })
+        }
+
+        main = () i32 {
+            n = comptime count
+            n != 4 ? | true { return 1 } | false { }
+            all = comptime joined
+            all != "NorthEastSouthWest" ? | true { return 2 } | false { }
+            return 0
+        }
+    "#;
+
+    let result = run_expecting_success(source);
+    assert_eq!(result.exit_code, 0, "Comptime variant loop check {} failed", result.exit_code);
+}
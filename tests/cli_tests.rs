@@ -0,0 +1,832 @@
+//! Tests that drive the `zen` binary through its command-line flags.
+
+use std::fs;
+use std::path::PathBuf;
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Command, Output, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Global counter for unique source file names (thread-safe)
+static TEST_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Write `source` to a fresh temporary `.zen` file and return its path.
+fn write_source(source: &str) -> PathBuf {
+    let test_id = TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
+    let path = std::env::temp_dir().join(format!(
+        "zen_cli_test_{}_{}.zen",
+        std::process::id(),
+        test_id
+    ));
+    fs::write(&path, source).expect("failed to write test source");
+    path
+}
+
+/// Run the `zen` binary with `args`, replacing `{src}` with the path of `source`.
+fn run_zen(args: &[&str], source: &str) -> Output {
+    let path = write_source(source);
+    let path_str = path.to_string_lossy().to_string();
+    let args: Vec<String> = args.iter().map(|a| a.replace("{src}", &path_str)).collect();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_zen"))
+        .args(&args)
+        .output()
+        .expect("failed to run zen");
+    fs::remove_file(&path).ok();
+    output
+}
+
+// ============================================================================
+// --dump-ast
+// ============================================================================
+
+#[test]
+fn test_dump_ast_shows_inferred_types() {
+    let source = r#"
+        Point: { x: i32, y: i32 }
+
+        main = () i32 {
+            total = 40 + 2
+            return total
+        }
+    "#;
+
+    let output = run_zen(&["--dump-ast", "{src}"], source);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "zen --dump-ast failed: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(stdout.contains("Point: { x: i32, y: i32 }"), "missing struct in dump:\n{}", stdout);
+    assert!(stdout.contains("main = () i32 {"), "missing signature in dump:\n{}", stdout);
+    assert!(stdout.contains("total: i32"), "missing inferred local type in dump:\n{}", stdout);
+}
+
+// ============================================================================
+// --dump-cfg
+// ============================================================================
+
+#[test]
+fn test_dump_cfg_emits_dot_edges() {
+    let source = r#"
+        sign = (x: i32) i32 {
+            result:: i32 = 0
+            x > 0 ?
+                | true { result = 1 }
+                | false { result = -1 }
+            return result
+        }
+
+        main = () i32 {
+            return sign(5) - 1
+        }
+    "#;
+
+    let output = run_zen(&["--dump-cfg=sign", "{src}"], source);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "zen --dump-cfg failed: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(stdout.starts_with("digraph \"sign\" {"), "not a DOT graph:\n{}", stdout);
+    assert!(stdout.contains("\"entry\" -> "), "entry block has no outgoing edge:\n{}", stdout);
+    assert!(stdout.contains("-> \"pattern_merge\";"), "arms should rejoin at the merge block:\n{}", stdout);
+    assert!(stdout.trim_end().ends_with('}'), "unterminated graph:\n{}", stdout);
+}
+
+#[test]
+fn test_dump_cfg_honors_error_format_json() {
+    let source = "main = () i32 {\n    return 0\n}\n";
+
+    let output = run_zen(&["--error-format=json", "--dump-cfg=missing", "{src}"], source);
+    assert!(!output.status.success(), "dumping an unknown function should fail");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let line = stderr.lines().next().expect("no error emitted");
+    let error: serde_json::Value = serde_json::from_str(line)
+        .unwrap_or_else(|e| panic!("stderr is not JSON ({}): {}", e, stderr));
+    assert!(
+        error["message"].as_str().is_some_and(|m| m.contains("missing")),
+        "bad message: {}",
+        error
+    );
+}
+
+// ============================================================================
+// --time-passes
+// ============================================================================
+
+#[test]
+fn test_time_passes_reports_without_changing_result() {
+    let source = r#"
+        { io } = @std.io
+
+        main = () i32 {
+            io.println("hello")
+            return 7
+        }
+    "#;
+
+    let plain = run_zen(&["{src}"], source);
+    let timed = run_zen(&["--time-passes", "{src}"], source);
+
+    assert_eq!(plain.status.code(), Some(7));
+    assert_eq!(timed.status.code(), plain.status.code(), "exit code changed under --time-passes");
+    assert_eq!(timed.stdout, plain.stdout, "program output changed under --time-passes");
+
+    let stderr = String::from_utf8_lossy(&timed.stderr);
+    for pass in ["lexing", "parsing", "type checking", "codegen", "total"] {
+        assert!(stderr.contains(pass), "missing '{}' timing in:\n{}", pass, stderr);
+    }
+}
+
+// ============================================================================
+// Object cache
+// ============================================================================
+
+#[test]
+fn test_object_cache_reused_for_unchanged_source() {
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+    let src = dir.path().join("prog.zen");
+    let compile = || {
+        Command::new(env!("CARGO_BIN_EXE_zen"))
+            .current_dir(dir.path())
+            .args([src.to_str().unwrap(), "-o", "prog"])
+            .output()
+            .expect("failed to run zen")
+    };
+    let run = || {
+        Command::new(dir.path().join("target/prog"))
+            .status()
+            .expect("failed to run compiled program")
+            .code()
+    };
+
+    fs::write(&src, "main = () i32 {\n    return 5\n}\n").unwrap();
+    let first = compile();
+    assert!(first.status.success(), "first compile failed: {}", String::from_utf8_lossy(&first.stderr));
+    assert!(!String::from_utf8_lossy(&first.stdout).contains("Cache hit"));
+
+    let second = compile();
+    assert!(second.status.success(), "second compile failed: {}", String::from_utf8_lossy(&second.stderr));
+    assert!(String::from_utf8_lossy(&second.stdout).contains("Cache hit"), "unchanged source was recompiled");
+    assert_eq!(run(), Some(5));
+
+    // Any source change invalidates the cached object
+    fs::write(&src, "main = () i32 {\n    return 6\n}\n").unwrap();
+    let third = compile();
+    assert!(third.status.success(), "third compile failed: {}", String::from_utf8_lossy(&third.stderr));
+    assert!(!String::from_utf8_lossy(&third.stdout).contains("Cache hit"), "changed source hit the cache");
+    assert_eq!(run(), Some(6));
+}
+
+#[test]
+fn test_object_cache_invalidated_by_imported_module_change() {
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+    fs::create_dir(dir.path().join("stdlib")).unwrap();
+    let module = dir.path().join("stdlib/shapes.zen");
+    fs::write(dir.path().join("prog.zen"), "{ sides } = @std.shapes\n\nmain = () i32 {\n    return sides()\n}\n").unwrap();
+    let compile = || {
+        Command::new(env!("CARGO_BIN_EXE_zen"))
+            .current_dir(dir.path())
+            .args(["prog.zen", "-o", "prog"])
+            .output()
+            .expect("failed to run zen")
+    };
+    let run = || {
+        Command::new(dir.path().join("target/prog"))
+            .status()
+            .expect("failed to run compiled program")
+            .code()
+    };
+
+    fs::write(&module, "sides = () i32 {\n    return 3\n}\n").unwrap();
+    let first = compile();
+    assert!(first.status.success(), "first compile failed: {}", String::from_utf8_lossy(&first.stderr));
+    let second = compile();
+    assert!(String::from_utf8_lossy(&second.stdout).contains("Cache hit"), "unchanged program was recompiled");
+    assert_eq!(run(), Some(3));
+
+    // Editing only the imported module must not reuse the stale object
+    fs::write(&module, "sides = () i32 {\n    return 4\n}\n").unwrap();
+    let third = compile();
+    assert!(third.status.success(), "third compile failed: {}", String::from_utf8_lossy(&third.stderr));
+    assert!(!String::from_utf8_lossy(&third.stdout).contains("Cache hit"), "changed import hit the cache");
+    assert_eq!(run(), Some(4));
+}
+
+#[test]
+fn test_object_cache_evicts_stale_objects() {
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+    let cache = dir.path().join("target/.zen-cache");
+    fs::create_dir_all(&cache).unwrap();
+
+    // An object nobody has used for a month
+    let stale = cache.join("0000000000000000.o");
+    fs::write(&stale, b"stale object").unwrap();
+    let month_ago = std::time::SystemTime::now() - std::time::Duration::from_secs(30 * 24 * 60 * 60);
+    fs::File::options().append(true).open(&stale).unwrap().set_modified(month_ago).unwrap();
+
+    fs::write(dir.path().join("prog.zen"), "main = () i32 {\n    return 5\n}\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_zen"))
+        .current_dir(dir.path())
+        .args(["prog.zen", "-o", "prog"])
+        .output()
+        .expect("failed to run zen");
+    assert!(output.status.success(), "compile failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    assert!(!stale.exists(), "stale cached object was not evicted");
+    let cached = fs::read_dir(&cache).unwrap().count();
+    assert_eq!(cached, 1, "the fresh object should be the only one left");
+}
+
+// ============================================================================
+// --stdin
+// ============================================================================
+
+/// Run the `zen` binary with `args`, piping `source` to its stdin.
+fn run_zen_with_stdin(args: &[&str], source: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_zen"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to run zen");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(source.as_bytes())
+        .expect("failed to write to zen stdin");
+    child.wait_with_output().expect("failed to wait for zen")
+}
+
+#[test]
+fn test_stdin_program_runs() {
+    let source = r#"
+        { io } = @std.io
+
+        main = () i32 {
+            io.println("from stdin")
+            return 3
+        }
+    "#;
+
+    for flag in ["-", "--stdin"] {
+        let output = run_zen_with_stdin(&[flag], source);
+        assert_eq!(output.status.code(), Some(3), "zen {} exited unexpectedly: {}", flag, String::from_utf8_lossy(&output.stderr));
+        assert!(String::from_utf8_lossy(&output.stdout).contains("from stdin"), "zen {} printed the wrong output", flag);
+    }
+}
+
+#[test]
+fn test_stdin_program_reports_errors() {
+    let output = run_zen_with_stdin(&["-"], "main = () i32 {\n    return missing\n}\n");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Compilation error"), "unexpected stderr: {}", String::from_utf8_lossy(&output.stderr));
+}
+
+// ============================================================================
+// zen watch
+// ============================================================================
+
+#[test]
+fn test_watch_recompiles_on_change() {
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+    let src = dir.path().join("watched.zen");
+    fs::write(&src, "main = () i32 {\n    return 0\n}\n").unwrap();
+
+    // Stop after the initial build and one rebuild; plain errors keep them on stdout
+    let mut child = Command::new(env!("CARGO_BIN_EXE_zen"))
+        .args(["--color=never", "watch", src.to_str().unwrap()])
+        .env("ZEN_WATCH_MAX_BUILDS", "2")
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to run zen watch");
+    let mut lines = BufReader::new(child.stdout.take().unwrap()).lines();
+    let mut next_build_line = || {
+        lines
+            .by_ref()
+            .map(|line| line.expect("failed to read zen watch output"))
+            .find(|line| line.contains("[build"))
+            .expect("zen watch exited before reporting a build")
+    };
+
+    let first = next_build_line();
+    assert!(first.contains("[build 1]") && first.contains("compiled"), "unexpected first build: {}", first);
+
+    // Break the program; the watcher should notice and report the error
+    fs::write(&src, "main = () i32 {\n    return missing\n}\n").unwrap();
+    let second = next_build_line();
+    assert!(second.contains("[build 2]") && second.contains("Compilation error"), "unexpected rebuild: {}", second);
+
+    assert!(child.wait().expect("failed to wait for zen watch").success());
+}
+
+#[test]
+fn test_watch_honors_error_format() {
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+    let src = dir.path().join("watched.zen");
+    fs::write(&src, "main = () i32 {\n    return missing_value\n}\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_zen"))
+        .args(["--error-format=json", "watch", src.to_str().unwrap()])
+        .env("ZEN_WATCH_MAX_BUILDS", "1")
+        .output()
+        .expect("failed to run zen watch");
+    assert!(output.status.success(), "watch should keep running past a failed build");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("[build 1]") && stdout.contains("failed"), "unexpected stdout: {}", stdout);
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let line = stderr.lines().next().expect("no error emitted");
+    let error: serde_json::Value = serde_json::from_str(line)
+        .unwrap_or_else(|e| panic!("stderr is not JSON ({}): {}", e, stderr));
+    assert_eq!(error["severity"], "error");
+    assert!(
+        error["message"].as_str().is_some_and(|m| m.contains("missing_value")),
+        "bad message: {}",
+        error
+    );
+}
+
+// ============================================================================
+// --error-format=json
+// ============================================================================
+
+#[test]
+fn test_error_format_json_reports_parseable_errors() {
+    let source = "main = () i32 {\n    return missing_value\n}\n";
+
+    let output = run_zen(&["--error-format=json", "{src}"], source);
+    assert!(!output.status.success(), "erroring program should fail");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let line = stderr.lines().next().expect("no error emitted");
+    let error: serde_json::Value = serde_json::from_str(line)
+        .unwrap_or_else(|e| panic!("stderr is not JSON ({}): {}", e, stderr));
+
+    assert_eq!(error["severity"], "error");
+    assert!(error["file"].as_str().is_some_and(|f| f.ends_with(".zen")), "bad file: {}", error);
+    assert!(
+        error["message"].as_str().is_some_and(|m| m.contains("missing_value")),
+        "bad message: {}",
+        error
+    );
+    // The error points at the `return` statement, with a 1-based column
+    assert_eq!(error["line"], 2, "bad line: {}", error);
+    assert_eq!(error["column"], 5, "bad column: {}", error);
+}
+
+// ============================================================================
+// --color
+// ============================================================================
+
+/// Remove ANSI escape sequences so columns can be compared as printed
+fn strip_ansi(text: &str) -> String {
+    let mut out = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|&c| c == 'm');
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[test]
+fn test_color_errors_underline_span_with_caret() {
+    let source = "main = () i32 {\n    return missing_value\n}\n";
+
+    let output = run_zen(&["--color=always", "{src}"], source);
+    assert!(!output.status.success(), "erroring program should fail");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("\x1b[1;31m"), "expected ANSI colors:\n{}", stderr);
+
+    let plain = strip_ansi(&stderr);
+    let lines: Vec<&str> = plain.lines().collect();
+    assert!(lines[0].starts_with("error: "), "missing header:\n{}", plain);
+    assert!(lines[1].contains(":2:5"), "missing location:\n{}", plain);
+
+    let source_idx = lines
+        .iter()
+        .position(|l| l.ends_with("    return missing_value"))
+        .unwrap_or_else(|| panic!("missing source line:\n{}", plain));
+    let caret_line = lines[source_idx + 1];
+    assert_eq!(
+        caret_line.find('^'),
+        lines[source_idx].find("return"),
+        "caret should point at the start of the statement:\n{}",
+        plain
+    );
+    assert!(caret_line.trim_end().ends_with("^~~~~~"), "caret should span `return`:\n{}", plain);
+}
+
+#[test]
+fn test_color_never_keeps_plain_errors() {
+    let source = "main = () i32 {\n    return missing_value\n}\n";
+
+    let output = run_zen(&["--color=never", "{src}"], source);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "erroring program should fail");
+    assert!(!stderr.contains('\x1b'), "unexpected ANSI escapes:\n{}", stderr);
+    assert!(!stderr.contains('^'), "unexpected caret without color:\n{}", stderr);
+}
+
+// ============================================================================
+// Warnings and --deny-warnings
+// ============================================================================
+
+#[test]
+fn test_unused_variable_warns_without_failing() {
+    let source = r#"
+        main = () i32 {
+            unused = 41
+            return 0
+        }
+    "#;
+
+    let output = run_zen(&["{src}"], source);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "warnings alone must not fail the build: {}", stderr);
+    assert!(stderr.contains("warning: unused variable: `unused`"), "missing warning:\n{}", stderr);
+}
+
+#[test]
+fn test_deny_warnings_fails_on_unused_variable() {
+    let source = r#"
+        main = () i32 {
+            unused = 41
+            return 0
+        }
+    "#;
+
+    let output = run_zen(&["--deny-warnings", "{src}"], source);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "--deny-warnings should fail on an unused variable");
+    assert!(stderr.contains("unused variable: `unused`"), "missing warning:\n{}", stderr);
+}
+
+#[test]
+fn test_deny_warnings_accepts_used_variables() {
+    let source = r#"
+        { io } = @std.io
+
+        main = () i32 {
+            shown = 7
+            io.println("shown: ${shown}")
+            _ignored = 1
+            total ::= 0
+            total = total + 3
+            return total
+        }
+    "#;
+
+    let output = run_zen(&["--deny-warnings", "{src}"], source);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(3), "unexpected failure:\n{}", stderr);
+    assert!(!stderr.contains("warning"), "unexpected warning:\n{}", stderr);
+}
+
+// ============================================================================
+// Multiple input files
+// ============================================================================
+
+#[test]
+fn test_multiple_input_files_link_into_one_executable() {
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+    fs::write(
+        dir.path().join("math.zen"),
+        "triple = (n: i32) i32 {\n    return n * 3\n}\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("main.zen"),
+        "main = () i32 {\n    return triple(4)\n}\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_zen"))
+        .current_dir(dir.path())
+        .args(["main.zen", "math.zen", "-o", "prog"])
+        .output()
+        .expect("failed to run zen");
+    assert!(output.status.success(), "multi-file compile failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let status = Command::new(dir.path().join("target/prog"))
+        .status()
+        .expect("failed to run compiled program");
+    assert_eq!(status.code(), Some(12));
+}
+
+#[test]
+fn test_multiple_input_files_reject_duplicate_symbols() {
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+    fs::write(dir.path().join("a.zen"), "helper = () i32 {\n    return 1\n}\n\nmain = () i32 {\n    return helper()\n}\n").unwrap();
+    fs::write(dir.path().join("b.zen"), "helper = () i32 {\n    return 2\n}\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_zen"))
+        .current_dir(dir.path())
+        .args(["a.zen", "b.zen", "-o", "prog"])
+        .output()
+        .expect("failed to run zen");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "duplicate symbol should fail the build");
+    assert!(stderr.contains("Duplicate declaration: helper"), "unexpected error:\n{}", stderr);
+}
+
+// ============================================================================
+// zen ir
+// ============================================================================
+
+#[test]
+fn test_ir_subcommand_prints_llvm_ir() {
+    let source = r#"
+        answer_plus = (n: i32) i32 {
+            return n + 42
+        }
+
+        main = () i32 {
+            return answer_plus(0) - 42
+        }
+    "#;
+
+    let output = run_zen(&["ir", "{src}"], source);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "zen ir failed: {}", String::from_utf8_lossy(&output.stderr));
+    let defines: Vec<&str> = stdout.lines().filter(|l| l.starts_with("define")).collect();
+    assert!(defines.iter().any(|l| l.contains("@answer_plus(")), "function missing from IR:\n{}", stdout);
+    assert!(defines.iter().any(|l| l.contains("@main(")), "main missing from IR:\n{}", stdout);
+}
+
+// ============================================================================
+// zen test
+// ============================================================================
+
+#[test]
+fn test_test_subcommand_reports_pass_and_fail_counts() {
+    let source = r#"
+        test_addition = () i32 {
+            1 + 1 == 2 ? | true { return 0 } | false { return 1 }
+        }
+
+        test_comparison = () bool {
+            return 3 > 2
+        }
+
+        test_broken = () i32 {
+            return 1
+        }
+
+        helper = () i32 {
+            return 7
+        }
+    "#;
+
+    let output = run_zen(&["test", "{src}"], source);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(1), "a failing test should fail the run:\n{}", stdout);
+    assert!(stdout.contains("running 3 tests"), "helper should not run as a test:\n{}", stdout);
+    assert!(stdout.contains("test test_broken ... FAILED"), "missing failure:\n{}", stdout);
+    assert!(stdout.contains("test result: FAILED. 2 passed; 1 failed"), "wrong summary:\n{}", stdout);
+}
+
+#[test]
+fn test_test_subcommand_checks_result_returns() {
+    let source = r#"
+        test_ok = () Result<i32, i32> {
+            return Result.Ok(1)
+        }
+
+        test_err = () Result<i32, i32> {
+            return Result.Err(2)
+        }
+    "#;
+
+    let output = run_zen(&["test", "{src}"], source);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(1), "an Err result should fail the run:\n{}", stdout);
+    assert!(stdout.contains("test test_ok ... ok"), "Ok should pass:\n{}", stdout);
+    assert!(stdout.contains("test test_err ... FAILED"), "Err should fail:\n{}", stdout);
+}
+
+#[test]
+fn test_test_subcommand_rejects_unsupported_return_type() {
+    let output = run_zen(&["test", "{src}"], "test_ratio = () f64 {\n    return 0.5\n}\n");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "an f64 test should be rejected");
+    assert!(stderr.contains("test_ratio"), "error should name the test:\n{}", stderr);
+}
+
+// ============================================================================
+// zen bench
+// ============================================================================
+
+#[test]
+fn test_bench_subcommand_reports_timings() {
+    let source = r#"
+        bench_sum = () i64 {
+            total:: i64 = 0
+            i:: i64 = 0
+            loop(() {
+                i >= 1000 ? | true { break } | false { }
+                total = total + i
+                i = i + 1
+            })
+            return total
+        }
+    "#;
+
+    let output = run_zen(&["bench", "{src}", "--iterations=5"], source);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "zen bench failed: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(stdout.contains("running 1 benchmarks (5 iterations each)"), "missing header:\n{}", stdout);
+    let line = stdout.lines().find(|l| l.starts_with("bench bench_sum")).unwrap_or_else(|| panic!("no timing line:\n{}", stdout));
+    assert!(line.contains("avg") && line.contains("min"), "timing line lacks avg/min: {}", line);
+}
+
+// ============================================================================
+// --instrument-coverage
+// ============================================================================
+
+#[test]
+fn test_instrument_coverage_counts_function_calls() {
+    let source = r#"
+        called = (n: i32) i32 {
+            return n + 1
+        }
+
+        never_called = (n: i32) i32 {
+            return n - 1
+        }
+
+        main = () i32 {
+            a = called(1)
+            b = called(a)
+            return b - 3
+        }
+    "#;
+
+    let output = run_zen(&["--instrument-coverage", "{src}"], source);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "instrumented run failed: {}", stderr);
+    assert!(stderr.contains("coverage: main 1\n"), "missing main count:\n{}", stderr);
+    assert!(stderr.contains("coverage: called 2\n"), "missing called count:\n{}", stderr);
+    assert!(stderr.contains("coverage: never_called 0\n"), "uncalled function should have zero count:\n{}", stderr);
+}
+
+// ============================================================================
+// REPL
+// ============================================================================
+
+#[test]
+fn test_repl_keeps_definitions_between_lines() {
+    let input = "add = (a: i64, b: i64) i64 { return a + b }\n\
+                 add(2, 3)\n\
+                 add = (a: i64, b: i64) i64 { return a * b }\n\
+                 add(2, 3)\n\
+                 exit\n";
+
+    let output = run_zen_with_stdin(&[], input);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "zen repl failed: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(!stdout.contains("Error"), "repl reported an error:\n{}", stdout);
+    let results: Vec<&str> = stdout.lines().filter_map(|line| line.split("=> ").nth(1)).collect();
+    assert_eq!(results, ["5", "6"], "unexpected repl output:\n{}", stdout);
+}
+
+#[test]
+fn test_repl_reads_multi_line_definitions() {
+    let input = "double = (n: i64) i64 {\n\
+                 \x20   return n * 2\n\
+                 }\n\
+                 double(21)\n\
+                 exit\n";
+
+    let output = run_zen_with_stdin(&[], input);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("...> "), "expected a continuation prompt:\n{}", stdout);
+    assert!(!stdout.contains("Error"), "repl reported an error:\n{}", stdout);
+    assert!(stdout.contains("=> 42"), "unexpected repl output:\n{}", stdout);
+}
+
+// ============================================================================
+// -O optimization levels
+// ============================================================================
+
+/// The body of `main` in textual LLVM IR
+fn main_body(ir: &str) -> &str {
+    ir.split("@main(").nth(1).and_then(|rest| rest.split("\n}").next()).expect("main should be defined")
+}
+
+#[test]
+fn test_opt_level_removes_dead_code() {
+    let source = "main = () i32 {\n    unused = 6 * 7 + 1000\n    return 5\n}\n";
+
+    let debug = run_zen(&["ir", "{src}"], source);
+    assert!(debug.status.success(), "zen ir failed: {}", String::from_utf8_lossy(&debug.stderr));
+    let debug_ir = String::from_utf8_lossy(&debug.stdout);
+    assert!(main_body(&debug_ir).contains("1042"), "-O0 should keep the dead store:\n{}", debug_ir);
+
+    let optimized = run_zen(&["ir", "-O2", "{src}"], source);
+    assert!(optimized.status.success(), "zen ir -O2 failed: {}", String::from_utf8_lossy(&optimized.stderr));
+    let optimized_ir = String::from_utf8_lossy(&optimized.stdout);
+    let body = main_body(&optimized_ir);
+    assert!(!body.contains("1042") && !body.contains("alloca"), "-O2 kept dead code:\n{}", body);
+    assert!(body.contains("ret i32 5"), "unexpected optimized main:\n{}", body);
+}
+
+#[test]
+fn test_opt_level_build_links_and_runs() {
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+    fs::write(dir.path().join("prog.zen"), "main = () i32 {\n    return 5\n}\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_zen"))
+        .current_dir(dir.path())
+        .args(["prog.zen", "-O2", "-o", "prog"])
+        .output()
+        .expect("failed to run zen");
+    assert!(output.status.success(), "-O2 compile failed: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("(-O2)"), "success message should name the level");
+
+    let status = Command::new(dir.path().join("target/prog"))
+        .status()
+        .expect("failed to run compiled program");
+    assert_eq!(status.code(), Some(5));
+}
+
+#[test]
+fn test_unknown_opt_level_is_reported() {
+    let output = run_zen(&["ir", "-O4", "{src}"], "main = () i32 {\n    return 5\n}\n");
+    assert_eq!(output.status.code(), Some(2), "-O4 should be rejected");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Unknown optimization level '-O4'"));
+}
+
+// ============================================================================
+// --emit-llvm / --emit-asm
+// ============================================================================
+
+#[test]
+fn test_emit_llvm_writes_ir_without_linking() {
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+    fs::write(dir.path().join("prog.zen"), "main = () i32 {\n    return 5\n}\n").unwrap();
+
+    // An empty PATH makes sure the linker is never needed
+    let output = Command::new(env!("CARGO_BIN_EXE_zen"))
+        .current_dir(dir.path())
+        .env("PATH", "")
+        .args(["prog.zen", "-o", "prog", "--emit-llvm"])
+        .output()
+        .expect("failed to run zen");
+    assert!(output.status.success(), "--emit-llvm failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let ir = fs::read_to_string(dir.path().join("target/prog.ll")).expect("prog.ll should be written");
+    assert!(ir.contains("define i32 @main("), "unexpected IR:\n{}", ir);
+    assert!(!dir.path().join("target/prog").exists(), "--emit-llvm should not link an executable");
+}
+
+#[test]
+fn test_emit_asm_writes_assembly() {
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+    fs::write(dir.path().join("prog.zen"), "main = () i32 {\n    return 5\n}\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_zen"))
+        .current_dir(dir.path())
+        .args(["--emit-asm", "-o", "prog", "prog.zen"])
+        .output()
+        .expect("failed to run zen");
+    assert!(output.status.success(), "--emit-asm failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let asm = fs::read_to_string(dir.path().join("target/prog.s")).expect("prog.s should be written");
+    assert!(asm.contains("main"), "unexpected assembly:\n{}", asm);
+}
+
+// ============================================================================
+// Exit code of main
+// ============================================================================
+
+#[test]
+fn test_result_main_exit_code() {
+    let err = run_zen(&["{src}"], "main = () Result<i32, i32> {\n    return Result.Err(3)\n}\n");
+    assert_eq!(err.status.code(), Some(1), "stderr: {}", String::from_utf8_lossy(&err.stderr));
+
+    let ok = run_zen(&["{src}"], "main = () Result<i32, i32> {\n    return Result.Ok(7)\n}\n");
+    assert_eq!(ok.status.code(), Some(0), "stderr: {}", String::from_utf8_lossy(&ok.stderr));
+}
+
+#[test]
+fn test_result_main_exit_code_native() {
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+    for (body, expected) in [("return Result.Err(3)", 1), ("return Result.Ok(7)", 0)] {
+        fs::write(dir.path().join("prog.zen"), format!("main = () Result<i32, i32> {{\n    {}\n}}\n", body)).unwrap();
+        let output = Command::new(env!("CARGO_BIN_EXE_zen"))
+            .current_dir(dir.path())
+            .args(["prog.zen", "-o", "prog"])
+            .output()
+            .expect("failed to run zen");
+        assert!(output.status.success(), "compile failed: {}", String::from_utf8_lossy(&output.stderr));
+
+        let status = Command::new(dir.path().join("target/prog"))
+            .status()
+            .expect("failed to run compiled program");
+        assert_eq!(status.code(), Some(expected), "main body: {}", body);
+    }
+}
@@ -32,6 +32,14 @@ fn compile_code(code: &str) -> Result<(), CompileError> {
     Ok(())
 }
 
+/// Compile Zen code and return the textual LLVM IR of the module
+fn compile_to_ir(code: &str) -> Result<String, CompileError> {
+    let context = Context::create();
+    let compiler = Compiler::new(&context);
+    let program = Parser::new(Lexer::new(code)).parse_program()?;
+    Ok(compiler.get_module(&program)?.print_to_string().to_string())
+}
+
 #[test]
 fn test_pattern_matching_compiles() {
     let code = r#"
@@ -238,3 +246,174 @@ fn test_pattern_matching_phi_node_basic_blocks() {
         result.err()
     );
 }
+
+#[test]
+fn test_unimplemented_expression_reports_span() {
+    let code = r#"
+        main = () i32 {
+            items = 3
+            items.loop((item) {
+                item
+            })
+            return 0
+        }
+    "#;
+
+    // Expressions codegen cannot lower yet must say so and point at the source,
+    // rather than surfacing as a location-less internal error
+    let err = compile_code(code).expect_err("collection.loop() should not compile yet");
+    assert!(
+        matches!(err, CompileError::UnsupportedFeature(..)),
+        "Expected an unsupported-feature error, got: {:?}",
+        err
+    );
+    assert!(err.span().is_some(), "Unsupported expression error should carry a span");
+}
+
+#[test]
+fn test_core_assert_message_reaches_ir() {
+    let code = r#"
+        { core } = @std
+
+        main = () i32 {
+            x = 2
+            core.assert(x > 0)
+            core.assert(x == 1, "x must be one")
+            return 0
+        }
+    "#;
+
+    let ir = compile_to_ir(code).expect("core.assert should compile");
+    assert!(ir.contains("x must be one"), "message global missing from IR:\n{}", ir);
+    assert!(ir.contains("assert_fail"), "no failure block in IR:\n{}", ir);
+    assert!(ir.contains("@fputs") && ir.contains("@stderr"), "message is not printed to stderr:\n{}", ir);
+}
+
+#[test]
+fn test_core_assert_through_alias() {
+    let code = r#"
+        { core as c } = @std
+
+        main = () i32 {
+            c.assert(1 < 2, "ordering is broken")
+            return 0
+        }
+    "#;
+
+    let ir = compile_to_ir(code).expect("aliased core.assert should compile");
+    assert!(ir.contains("assert_fail"), "aliased assert was not lowered:\n{}", ir);
+}
+
+#[test]
+fn test_print_float_g_uses_compact_format() {
+    let code = r#"
+        { io } = @std
+
+        main = () i32 {
+            io.print_float_g(3.5)
+            io.print_float_prec(3.5, 2)
+            return 0
+        }
+    "#;
+
+    let ir = compile_to_ir(code).expect("float printing should compile");
+    assert!(ir.contains("c\"%.*g\\00\""), "print_float_g should format with %.*g:\n{}", ir);
+    assert!(ir.contains("c\"%.*f\\00\""), "print_float_prec should keep %.*f:\n{}", ir);
+}
+
+#[test]
+fn test_void_calls_produce_no_return_value() {
+    let code = r#"
+        { io } = @std
+
+        note = () void {
+            io.println("note")
+        }
+
+        forward = () void {
+            note()
+            return note()
+        }
+
+        main = () i32 {
+            forward()
+            return 0
+        }
+    "#;
+
+    let ir = compile_to_ir(code).expect("void calls in statement and return position should compile");
+    let forward = ir
+        .split("define void @forward(")
+        .nth(1)
+        .and_then(|rest| rest.split("\n}").next())
+        .expect("forward should be defined returning void");
+    assert!(forward.contains("ret void"), "forward should return void:\n{}", forward);
+    assert!(!forward.contains("ret i32"), "void return leaked a placeholder value:\n{}", forward);
+}
+
+#[test]
+fn test_derive_clone_generates_clone_function() {
+    let code = r#"
+        Point: {
+            x: i64,
+            y: i64,
+        }
+
+        comptime.derive_clone(Point)
+
+        main = () i32 {
+            p = Point { x: 1, y: 2 }
+            q = p.clone()
+            return q.x as i32
+        }
+    "#;
+
+    let ir = compile_to_ir(code).expect("derived clone should compile");
+    assert!(
+        ir.lines().any(|l| l.starts_with("define") && l.contains("@Point.clone(")),
+        "derive_clone should define Point.clone:\n{}",
+        ir
+    );
+    let main = ir
+        .split("@main(")
+        .nth(1)
+        .and_then(|rest| rest.split("\n}").next())
+        .expect("main should be defined");
+    assert!(main.contains("call") && main.contains("@Point.clone("), "p.clone() should call the generated method:\n{}", main);
+}
+
+#[test]
+fn test_void_arms_and_unit_payloads_compile() {
+    let code = r#"
+        { io } = @std
+
+        note = () void {
+            io.println("note")
+        }
+
+        maybe_note = (flag: bool) void {
+            flag ?
+                | true { note() }
+                | false { }
+        }
+
+        done = () Result<(), i32> {
+            return Result.Ok(())
+        }
+
+        main = () i32 {
+            maybe_note(true)
+            done() ?
+                | Ok(_) { return 0 }
+                | Err(code) { return code }
+        }
+    "#;
+
+    let ir = compile_to_ir(code).expect("void match arms and unit payloads should compile");
+    let maybe_note = ir
+        .split("define void @maybe_note(")
+        .nth(1)
+        .and_then(|rest| rest.split("\n}").next())
+        .expect("maybe_note should be defined returning void");
+    assert!(!maybe_note.contains("phi i32"), "void arms merged through a fake i32:\n{}", maybe_note);
+}
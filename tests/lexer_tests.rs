@@ -52,3 +52,28 @@ fn test_lexer_new_syntax() {
     assert_eq!(tokens[1], Token::Symbol('.'));
     assert_eq!(tokens[2], Token::Identifier("loop".to_string()));
 }
+
+#[test]
+fn test_lexer_decrement_only_without_operand() {
+    let minus = || Token::Operator("-".to_string());
+
+    // Statement position: a decrement
+    assert_eq!(
+        tokenize("x--\n"),
+        vec![Token::Identifier("x".to_string()), Token::Operator("--".to_string())]
+    );
+
+    // An operand follows: subtraction of a negation
+    assert_eq!(
+        tokenize("x--1"),
+        vec![Token::Identifier("x".to_string()), minus(), minus(), Token::Integer("1".to_string())]
+    );
+    assert_eq!(
+        tokenize("a--b"),
+        vec![Token::Identifier("a".to_string()), minus(), minus(), Token::Identifier("b".to_string())]
+    );
+    assert_eq!(
+        tokenize("a - -b"),
+        vec![Token::Identifier("a".to_string()), minus(), minus(), Token::Identifier("b".to_string())]
+    );
+}
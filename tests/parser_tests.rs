@@ -65,3 +65,48 @@ test = () void {
         }
     }
 }
+
+#[test]
+fn test_parse_import_alias() {
+    let code = r#"
+{ io, math as m } = @std
+"#;
+
+    let lexer = Lexer::new(code);
+    let mut parser = Parser::new(lexer);
+    let program = parser.parse_program().expect("aliased import should parse");
+
+    let imports: Vec<(&str, &str)> = program
+        .declarations
+        .iter()
+        .filter_map(|decl| match decl {
+            zen::ast::Declaration::ModuleImport {
+                alias, module_path, ..
+            } => Some((alias.as_str(), module_path.as_str())),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(imports, [("io", "@std.io"), ("m", "@std.math")]);
+
+    // Symbols imported from a specific module keep their own names
+    let code = "{ Range as R } = @std.core.iterator\n";
+    let mut parser = Parser::new(Lexer::new(code));
+    assert!(parser.parse_program().is_err(), "aliasing a symbol should be rejected");
+}
+
+#[test]
+fn test_parse_reexport() {
+    let code = "@export { println, print } from @std.io\n@export * from @std.math\n";
+    let mut parser = Parser::new(Lexer::new(code));
+    let program = parser.parse_program().expect("re-exports should parse");
+
+    match &program.declarations[..] {
+        [zen::ast::Declaration::Export { symbols, from }, zen::ast::Declaration::Export { symbols: all, from: all_from }] => {
+            assert_eq!(symbols, &["println", "print"]);
+            assert_eq!(from.as_deref(), Some("@std.io"));
+            assert_eq!(all, &["*"]);
+            assert_eq!(all_from.as_deref(), Some("@std.math"));
+        }
+        other => panic!("expected two exports, got {:?}", other),
+    }
+}